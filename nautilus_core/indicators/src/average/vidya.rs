@@ -0,0 +1,224 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::VecDeque;
+use std::fmt::Display;
+
+use nautilus_model::{
+    data::{bar::Bar, quote::QuoteTick, trade::TradeTick},
+    enums::PriceType,
+};
+
+use crate::indicator::Indicator;
+
+/// An adaptive exponential moving average whose smoothing factor is scaled by momentum
+/// (Chande's Variable Index Dynamic Average).
+///
+/// Unlike a fixed-period EMA, VIDYA shrinks its effective lookback in trending markets and
+/// stretches it in choppy ones by weighting the usual `alpha = 2 / (period + 1)` smoothing
+/// constant with the absolute value of the Chande Momentum Oscillator (CMO) computed over the
+/// same window of price differences.
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")
+)]
+pub struct Vidya {
+    pub value: f64,
+    pub count: usize,
+    pub initialized: bool,
+    has_inputs: bool,
+    period: usize,
+    price_type: PriceType,
+    alpha: f64,
+    diffs: VecDeque<f64>,
+    last_price: Option<f64>,
+}
+
+impl Display for Vidya {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.name(), self.period)
+    }
+}
+
+impl Indicator for Vidya {
+    fn name(&self) -> String {
+        stringify!(Vidya).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update(bar.close.as_f64());
+    }
+
+    fn handle_quote_tick(&mut self, quote: &QuoteTick) {
+        let price = match self.price_type {
+            PriceType::Bid => quote.bid_price.as_f64(),
+            PriceType::Ask => quote.ask_price.as_f64(),
+            _ => (quote.bid_price.as_f64() + quote.ask_price.as_f64()) / 2.0,
+        };
+        self.update(price);
+    }
+
+    fn handle_trade_tick(&mut self, trade: &TradeTick) {
+        self.update(trade.price.as_f64());
+    }
+
+    fn reset(&mut self) {
+        self.value = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+        self.diffs.clear();
+        self.last_price = None;
+    }
+}
+
+impl Vidya {
+    /// Creates a new [`Vidya`] instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `period` - The number of price differences used to compute the Chande Momentum
+    ///   Oscillator that scales the smoothing factor.
+    /// * `price_type` - The applicable price type (used by `handle_quote_tick`).
+    #[must_use]
+    pub fn new(period: usize, price_type: Option<PriceType>) -> Self {
+        Self {
+            value: 0.0,
+            count: 0,
+            has_inputs: false,
+            initialized: false,
+            period,
+            price_type: price_type.unwrap_or(PriceType::Mid),
+            alpha: 2.0 / (period as f64 + 1.0),
+            diffs: VecDeque::with_capacity(period),
+            last_price: None,
+        }
+    }
+
+    fn update(&mut self, price: f64) {
+        self.has_inputs = true;
+        self.count += 1;
+
+        if let Some(last_price) = self.last_price {
+            if self.diffs.len() == self.period {
+                self.diffs.pop_front();
+            }
+            self.diffs.push_back(price - last_price);
+        }
+        self.last_price = Some(price);
+
+        if self.diffs.len() < self.period {
+            return;
+        }
+
+        let sum_up: f64 = self.diffs.iter().filter(|&&d| d > 0.0).sum();
+        let sum_down: f64 = self.diffs.iter().filter(|&&d| d < 0.0).map(|d| d.abs()).sum();
+
+        // An all-equal window has no momentum to scale the smoothing factor by, so leave the
+        // value unchanged rather than seeding it -- seeding here would mark the indicator
+        // initialized off a window that never saw a real price move.
+        if sum_up + sum_down == 0.0 {
+            return;
+        }
+
+        let cmo = (sum_up - sum_down) / (sum_up + sum_down);
+        let abs_cmo = cmo.abs();
+
+        if !self.initialized {
+            self.value = price;
+            self.initialized = true;
+        } else {
+            self.value = self.alpha.mul_add(abs_cmo * price, (1.0 - self.alpha * abs_cmo) * self.value);
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::stubs::stub_trade_tick_eth_usdt;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_initialized() {
+        let vidya = Vidya::new(5, None);
+        let display_str = format!("{vidya}");
+        assert_eq!(display_str, "Vidya(5)");
+        assert_eq!(vidya.value, 0.0);
+        assert_eq!(vidya.count, 0);
+        assert!(!vidya.has_inputs);
+        assert!(!vidya.initialized);
+    }
+
+    #[rstest]
+    fn test_value_after_warmup() {
+        let mut vidya = Vidya::new(3, None);
+        let mut trade = stub_trade_tick_eth_usdt();
+
+        for price in [100.0, 101.0, 99.0, 102.0] {
+            trade.price = nautilus_model::types::price::Price::from(price.to_string().as_str());
+            vidya.handle_trade_tick(&trade);
+        }
+
+        assert!(vidya.initialized);
+        assert_eq!(vidya.count, 4);
+    }
+
+    #[rstest]
+    fn test_flat_prices_leave_value_unchanged() {
+        let mut vidya = Vidya::new(3, None);
+        let mut trade = stub_trade_tick_eth_usdt();
+        trade.price = nautilus_model::types::price::Price::from("100.0");
+
+        for _ in 0..5 {
+            vidya.handle_trade_tick(&trade);
+        }
+
+        assert!(!vidya.initialized);
+        assert_eq!(vidya.value, 0.0);
+    }
+
+    #[rstest]
+    fn test_reset() {
+        let mut vidya = Vidya::new(3, None);
+        let mut trade = stub_trade_tick_eth_usdt();
+
+        for price in [100.0, 101.0, 99.0, 102.0] {
+            trade.price = nautilus_model::types::price::Price::from(price.to_string().as_str());
+            vidya.handle_trade_tick(&trade);
+        }
+
+        vidya.reset();
+
+        assert_eq!(vidya.count, 0);
+        assert_eq!(vidya.value, 0.0);
+        assert!(!vidya.has_inputs);
+        assert!(!vidya.initialized);
+    }
+}
@@ -16,20 +16,246 @@
 use std::collections::VecDeque;
 use std::fmt::Display;
 
+use fixed::types::I80F48;
 use nautilus_core::nanos::UnixNanos;
 use nautilus_model::{
-    enums::OrderSide,
-    orderbook::book::OrderBook,
+    data::{quote::QuoteTick, trade::TradeTick},
+    enums::{AggressorSide, OrderSide},
+    orderbook::{book::OrderBook, level::BookLevel},
     types::price::Price,
 };
+use serde::{Deserialize, Serialize};
 
+use crate::book::virtual_amm::VirtualAmmPool;
 use crate::indicator::Indicator;
 
+/// Converts an `f64` into the fixed-point type used for deterministic score accumulation.
+fn fx(value: f64) -> I80F48 {
+    I80F48::from_num(value)
+}
+
+/// How far a partial (quote-tick-derived) `depth_recovery` ratio is blended toward the neutral
+/// midpoint `0.5` in [`MarketResilienceIndicator::calculate_normalized_metrics`]. `1.0` would use
+/// the raw ratio unchanged (no down-weighting); `0.0` would ignore it entirely in favor of
+/// `time_weight`/`spread_weight`.
+const QUOTE_DEPTH_DISCOUNT: f64 = 0.5;
+
+/// Returns the median of `values`, or `None` if the iterator is empty.
+fn median(values: impl Iterator<Item = f64>) -> Option<f64> {
+    let mut sorted: Vec<f64> = values.collect();
+    if sorted.is_empty() {
+        return None;
+    }
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let mid = sorted.len() / 2;
+    if sorted.len() % 2 == 0 {
+        Some((sorted[mid - 1] + sorted[mid]) / 2.0)
+    } else {
+        Some(sorted[mid])
+    }
+}
+
+/// A flattened snapshot of the book metrics the resilience logic actually consumes, captured
+/// instead of cloning the full `OrderBook` so depletion state stays cheap to hold and trivial to
+/// serialize for checkpointing.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+struct BookSnapshot {
+    spread: Option<f64>,
+    /// The book's total depth, expressed as quote-currency notional via the indicator's
+    /// [`AssetType`] so it is comparable across linear and inverse contracts.
+    depth: f64,
+    /// `true` when `depth` was derived from a single quote-tick level rather than a full book,
+    /// so [`MarketResilienceIndicator::calculate_normalized_metrics`] can down-weight the depth
+    /// term accordingly.
+    is_partial: bool,
+    ts_last: UnixNanos,
+}
+
+impl BookSnapshot {
+    fn from_book(book: &OrderBook, asset_type: &dyn AssetType) -> Self {
+        Self::from_book_and_amm(book, asset_type, None, 0)
+    }
+
+    /// Like [`Self::from_book`], but also folds in synthetic depth from `virtual_amm` (when set)
+    /// up to `amm_levels` levels per side, so hybrid venues that route across a CLOB and an AMM
+    /// report total executable depth rather than only the CLOB portion.
+    fn from_book_and_amm(
+        book: &OrderBook,
+        asset_type: &dyn AssetType,
+        virtual_amm: Option<&VirtualAmmPool>,
+        amm_levels: usize,
+    ) -> Self {
+        let mut depth: f64 = book
+            .bids()
+            .chain(book.asks())
+            .filter_map(|level| asset_type.notional(level.price.value.as_f64(), level.size()))
+            .sum();
+
+        if let Some(pool) = virtual_amm {
+            depth += pool
+                .synthetic_levels(OrderSide::Buy, amm_levels)
+                .into_iter()
+                .chain(pool.synthetic_levels(OrderSide::Sell, amm_levels))
+                .filter_map(|(price, size)| asset_type.notional(price, size))
+                .sum::<f64>();
+        }
+
+        Self {
+            spread: book.spread(),
+            depth,
+            is_partial: false,
+            ts_last: book.ts_last,
+        }
+    }
+}
+
+/// Converts a price/quantity pair at a single book level into quote-currency notional, so depth
+/// can be compared across contracts with different settlement conventions.
+pub trait AssetType: std::fmt::Debug {
+    /// Returns the quote-currency notional for `qty` contracts at `price`, or `None` if `price`
+    /// is not strictly positive.
+    fn notional(&self, price: f64, qty: f64) -> Option<f64>;
+}
+
+/// A linear contract, where notional is `price * qty * contract_size` (e.g. USDT-margined
+/// perpetuals, spot pairs).
+#[derive(Debug, Clone, Copy)]
+pub struct LinearAsset {
+    pub contract_size: f64,
+}
+
+impl AssetType for LinearAsset {
+    fn notional(&self, price: f64, qty: f64) -> Option<f64> {
+        if price > 0.0 {
+            Some(price * qty * self.contract_size)
+        } else {
+            None
+        }
+    }
+}
+
+/// An inverse (coin-margined) contract, where each contract is a fixed amount of quote currency
+/// and notional is `qty * contract_size / price` (e.g. BTCUSD coin-margined perpetuals).
+#[derive(Debug, Clone, Copy)]
+pub struct InverseAsset {
+    pub contract_size: f64,
+}
+
+impl AssetType for InverseAsset {
+    fn notional(&self, price: f64, qty: f64) -> Option<f64> {
+        if price > 0.0 {
+            Some(qty * self.contract_size / price)
+        } else {
+            None
+        }
+    }
+}
+
+/// An online estimator of a single quantile via the P² (piecewise-parabolic) algorithm, which
+/// tracks five markers instead of storing the full observation history. Used to adaptively
+/// threshold spread shocks against a running high quantile of recent spreads instead of a fixed
+/// multiplier.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+struct P2QuantileEstimator {
+    /// The target quantile, in `0..1` (e.g. `0.9` for the 90th percentile).
+    p: f64,
+    /// The number of observations seen so far.
+    count: usize,
+    /// Marker heights q1..q5.
+    q: [f64; 5],
+    /// Marker positions n1..n5.
+    n: [f64; 5],
+    /// Desired marker positions n'1..n'5.
+    np: [f64; 5],
+    /// Desired position increments per observation, dn1..dn5.
+    dn: [f64; 5],
+}
+
+impl P2QuantileEstimator {
+    fn new(p: f64) -> Self {
+        Self {
+            p,
+            count: 0,
+            q: [0.0; 5],
+            n: [1.0, 2.0, 3.0, 4.0, 5.0],
+            np: [1.0, 1.0 + 2.0 * p, 1.0 + 4.0 * p, 3.0 + 2.0 * p, 5.0],
+            dn: [0.0, p / 2.0, p, (1.0 + p) / 2.0, 1.0],
+        }
+    }
+
+    /// Returns the current quantile estimate (marker 3's height), or `None` until at least five
+    /// observations have been seen and the markers are initialized.
+    fn quantile(&self) -> Option<f64> {
+        if self.count < 5 { None } else { Some(self.q[2]) }
+    }
+
+    fn update(&mut self, x: f64) {
+        self.count += 1;
+
+        if self.count <= 5 {
+            self.q[self.count - 1] = x;
+            if self.count == 5 {
+                self.q.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            }
+            return;
+        }
+
+        let k = if x < self.q[0] {
+            self.q[0] = x;
+            0
+        } else if x >= self.q[4] {
+            self.q[4] = x;
+            3
+        } else {
+            (0..4)
+                .find(|&i| self.q[i] <= x && x < self.q[i + 1])
+                .unwrap_or(3)
+        };
+
+        for ni in self.n.iter_mut().skip(k + 1) {
+            *ni += 1.0;
+        }
+        for i in 0..5 {
+            self.np[i] += self.dn[i];
+        }
+
+        for i in 1..4 {
+            let d = self.np[i] - self.n[i];
+            if (d >= 1.0 && self.n[i + 1] - self.n[i] > 1.0)
+                || (d <= -1.0 && self.n[i - 1] - self.n[i] < -1.0)
+            {
+                let sign = if d >= 0.0 { 1.0 } else { -1.0 };
+                let parabolic = self.parabolic_height(i, sign);
+                self.q[i] = if self.q[i - 1] < parabolic && parabolic < self.q[i + 1] {
+                    parabolic
+                } else {
+                    self.linear_height(i, sign)
+                };
+                self.n[i] += sign;
+            }
+        }
+    }
+
+    /// The P² parabolic prediction formula for marker `i`'s new height.
+    fn parabolic_height(&self, i: usize, d: f64) -> f64 {
+        let (qm, q0, qp) = (self.q[i - 1], self.q[i], self.q[i + 1]);
+        let (nm, n0, np) = (self.n[i - 1], self.n[i], self.n[i + 1]);
+        q0 + d / (np - nm)
+            * ((n0 - nm + d) * (qp - q0) / (np - n0) + (np - n0 - d) * (q0 - qm) / (n0 - nm))
+    }
+
+    /// The linear fallback used when the parabolic prediction would leave `[q[i-1], q[i+1]]`.
+    fn linear_height(&self, i: usize, d: f64) -> f64 {
+        let j = (i as f64 + d) as usize;
+        self.q[i] + d * (self.q[j] - self.q[i]) / (self.n[j] - self.n[i])
+    }
+}
+
 /// Represents the state of market depletion monitoring.
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct DepletionState {
-    initial_book: Option<OrderBook>,
-    end_book: Option<OrderBook>,
+    initial_book: Option<BookSnapshot>,
+    end_book: Option<BookSnapshot>,
     depletion_side: OrderSide,
     recovery_side: OrderSide,
     initial_price: Price,
@@ -52,18 +278,47 @@ impl DepletionState {
         }
     }
 
-    fn set_initial(&mut self, book: OrderBook, side: OrderSide, price: Price) {
-        self.initial_book = Some(book.clone());
+    #[allow(clippy::too_many_arguments)]
+    fn set_initial(
+        &mut self,
+        book: &OrderBook,
+        side: OrderSide,
+        price: Price,
+        asset_type: &dyn AssetType,
+        virtual_amm: Option<&VirtualAmmPool>,
+        amm_levels: usize,
+    ) {
+        let snapshot = BookSnapshot::from_book_and_amm(book, asset_type, virtual_amm, amm_levels);
+        self.set_initial_snapshot(snapshot, side, price, book.ts_last);
+    }
+
+    fn set_end(
+        &mut self,
+        book: &OrderBook,
+        side: OrderSide,
+        asset_type: &dyn AssetType,
+        virtual_amm: Option<&VirtualAmmPool>,
+        amm_levels: usize,
+    ) {
+        let snapshot = BookSnapshot::from_book_and_amm(book, asset_type, virtual_amm, amm_levels);
+        self.set_end_snapshot(snapshot, side, book.ts_last);
+    }
+
+    /// Variant of [`Self::set_initial`] that takes an already-built [`BookSnapshot`], letting
+    /// callers without a full `OrderBook` (e.g. quote-tick mode) open a monitoring window.
+    fn set_initial_snapshot(&mut self, snapshot: BookSnapshot, side: OrderSide, price: Price, ts: UnixNanos) {
+        self.initial_book = Some(snapshot);
         self.depletion_side = side;
         self.initial_price = price;
-        self.start_time = Some(book.ts_last);
+        self.start_time = Some(ts);
         self.end_time = None;
     }
 
-    fn set_end(&mut self, book: OrderBook, side: OrderSide) {
-        self.end_book = Some(book.clone());
+    /// Variant of [`Self::set_end`] that takes an already-built [`BookSnapshot`].
+    fn set_end_snapshot(&mut self, snapshot: BookSnapshot, side: OrderSide, ts: UnixNanos) {
+        self.end_book = Some(snapshot);
         self.recovery_side = side;
-        self.end_time = Some(book.ts_last);
+        self.end_time = Some(ts);
     }
 
     fn elapsed(&self) -> UnixNanos {
@@ -94,6 +349,200 @@ impl DepletionState {
     }
 }
 
+/// An open measurement window tracking spread-recovery latency after an aggressive trade that
+/// consumed a significant fraction of top-of-book depth, recorded by `handle_trade_tick` and
+/// closed by the next `handle_book` or `handle_quote_tick` update that observes recovery or a
+/// timeout.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+struct TradeConsumptionEvent {
+    ts: UnixNanos,
+    /// The side considered depleted by the trade (opposite of the side the trade lifted).
+    depletion_side: OrderSide,
+    /// The rolling median spread observed just before the trade.
+    baseline_spread: f64,
+}
+
+/// Computes the final resilience score from the recovery metrics extracted by
+/// [`MarketResilienceIndicator::calculate_normalized_metrics`].
+///
+/// Implementations decide how to combine `normalized_time`, `spread_recovery`, and
+/// `depth_recovery` (each in `0..=1`) and how to bias the result by whether recovery happened on
+/// the same side as the depletion, letting strategies plug in custom scoring without forking the
+/// indicator's metric-extraction logic.
+pub trait ResilienceScoreAdapter: std::fmt::Debug {
+    /// Returns the resilience score, clamped to `0..=1` by the caller.
+    fn compute(
+        &self,
+        normalized_time: f64,
+        spread_recovery: f64,
+        depth_recovery: f64,
+        same_side: bool,
+    ) -> f64;
+}
+
+/// Reproduces the indicator's original behavior: a linear weighted sum of the recovery metrics
+/// plus a fixed bias depending on whether recovery happened on the same side as depletion.
+#[derive(Debug, Clone, Copy)]
+pub struct LinearScoreAdapter {
+    pub time_weight: f64,
+    pub depth_weight: f64,
+    pub spread_weight: f64,
+    pub same_side_bias: f64,
+    pub opposite_side_bias: f64,
+}
+
+impl ResilienceScoreAdapter for LinearScoreAdapter {
+    fn compute(
+        &self,
+        normalized_time: f64,
+        spread_recovery: f64,
+        depth_recovery: f64,
+        same_side: bool,
+    ) -> f64 {
+        let base_score = self.time_weight * normalized_time
+            + self.depth_weight * depth_recovery
+            + self.spread_weight * spread_recovery;
+        let bias = if same_side {
+            self.same_side_bias
+        } else {
+            self.opposite_side_bias
+        };
+        base_score + bias
+    }
+}
+
+/// A saturating adapter that rewards fast same-side recovery more aggressively than
+/// [`LinearScoreAdapter`] by pushing the linear weighted sum through a logistic curve before
+/// applying the side bias.
+#[derive(Debug, Clone, Copy)]
+pub struct LogisticScoreAdapter {
+    pub time_weight: f64,
+    pub depth_weight: f64,
+    pub spread_weight: f64,
+    pub steepness: f64,
+    pub same_side_bias: f64,
+    pub opposite_side_bias: f64,
+}
+
+impl ResilienceScoreAdapter for LogisticScoreAdapter {
+    fn compute(
+        &self,
+        normalized_time: f64,
+        spread_recovery: f64,
+        depth_recovery: f64,
+        same_side: bool,
+    ) -> f64 {
+        let base_score = self.time_weight * normalized_time
+            + self.depth_weight * depth_recovery
+            + self.spread_weight * spread_recovery;
+        let saturated = 1.0 / (1.0 + (-self.steepness * (base_score - 0.5)).exp());
+        let bias = if same_side {
+            self.same_side_bias
+        } else {
+            self.opposite_side_bias
+        };
+        saturated + bias
+    }
+}
+
+/// A serializable snapshot of a [`MarketResilienceIndicator`]'s state, captured by
+/// [`MarketResilienceIndicator::to_state`] and applied by [`MarketResilienceIndicator::from_state`]
+/// to resume a live session or backtest without replaying history.
+///
+/// Alongside the configured parameters, this carries the derived quantities the indicator's
+/// logic consumes -- spreads, depth counts, prices, and timestamps -- so `from_state` fully
+/// restores a resumed indicator's `has_inputs`/`initialized` status and warmed-up rolling window
+/// without needing to be reconstructed with matching arguments first. Following a flat-state
+/// checkpoint approach, this stores only those derived quantities, rather than
+/// cloning the (comparatively heavy) `OrderBook` objects it processes.
+#[repr(C)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")
+)]
+pub struct ResilienceIndicatorState {
+    pub score: f64,
+    pub bias_side: OrderSide,
+    pub depletion_side: OrderSide,
+    pub recovery_side: OrderSide,
+    pub recovery_time: UnixNanos,
+    pub count: usize,
+    pub initialized: bool,
+    pub has_inputs: bool,
+    /// Configuration parameters, captured so a resumed indicator does not depend on being
+    /// reconstructed with matching arguments before `from_state` is called.
+    pub timeout: UnixNanos,
+    pub spread_window_size: usize,
+    pub levels_to_consume: usize,
+    pub spread_increase_threshold: f64,
+    pub strong_resilience_threshold: f64,
+    pub weak_resilience_threshold: f64,
+    pub time_weight: f64,
+    pub depth_weight: f64,
+    pub spread_weight: f64,
+    pub same_side_bias: f64,
+    pub opposite_side_bias: f64,
+    pub impact_notional: Option<f64>,
+    pub ewma_alpha: Option<f64>,
+    pub ewma_k: f64,
+    pub trade_size_fraction: f64,
+    pub trade_spread_tolerance: f64,
+    pub trade_weight: f64,
+    pub quantile_threshold: Option<f64>,
+    pub is_spread_recovered: bool,
+    pub is_strong_reversal: bool,
+    pub is_depletion_continuing: bool,
+    pub recent_spreads: Vec<f64>,
+    pub ewma_mean: f64,
+    pub ewma_var: f64,
+    pub ewma_initialized: bool,
+    /// The depletion side of the in-progress monitoring window, if any.
+    pub monitor_depletion_side: OrderSide,
+    /// The recovery side of the in-progress monitoring window, if any.
+    pub monitor_recovery_side: OrderSide,
+    /// The price at which the in-progress monitoring window started.
+    pub monitor_initial_price: Price,
+    /// The start timestamp of the in-progress monitoring window, if one is running.
+    pub monitor_start_time: Option<UnixNanos>,
+    /// The end timestamp of the in-progress monitoring window, once recovery is detected.
+    pub monitor_end_time: Option<UnixNanos>,
+    /// The spread captured when the in-progress monitoring window started.
+    pub monitor_initial_spread: Option<f64>,
+    /// The book depth (quote-currency notional) captured when the in-progress monitoring window
+    /// started.
+    pub monitor_initial_depth: Option<f64>,
+    /// The spread captured when the in-progress monitoring window ended.
+    pub monitor_end_spread: Option<f64>,
+    /// The book depth (quote-currency notional) captured when the in-progress monitoring window
+    /// ended.
+    pub monitor_end_depth: Option<f64>,
+    /// The best bid price of the last book processed, if any.
+    pub previous_best_bid: Option<f64>,
+    /// The best ask price of the last book processed, if any.
+    pub previous_best_ask: Option<f64>,
+    /// The spread of the last book processed, if any.
+    pub previous_spread: Option<f64>,
+    /// The timestamp of the last book processed, if any.
+    pub previous_ts_last: Option<UnixNanos>,
+    /// The median trade-recovery score over the rolling recovery-time window.
+    pub trade_recovery_score: f64,
+    /// The book-depletion-only component of `score`, before the trade-recovery term is blended
+    /// in by [`MarketResilienceIndicator::recompute_score`].
+    pub resilience_score: f64,
+    /// The rolling window of closed trade-recovery latencies, in milliseconds.
+    pub trade_recovery_times: Vec<f64>,
+    /// The timestamp the open trade-consumption event started, if one is running.
+    pub open_trade_event_ts: Option<UnixNanos>,
+    /// The depletion side of the open trade-consumption event, if one is running.
+    pub open_trade_event_side: OrderSide,
+    /// The baseline spread captured when the open trade-consumption event started.
+    pub open_trade_event_baseline_spread: Option<f64>,
+    /// The online quantile estimator's internal markers, when `quantile_threshold` is set. Not
+    /// exposed to Python, since resuming the P² markers mid-warm-up is a Rust-only concern.
+    pub(crate) quantile_estimator: Option<P2QuantileEstimator>,
+}
+
 /// Market resilience indicator that analyzes order book changes to detect market depletion and recovery.
 #[repr(C)]
 #[derive(Debug)]
@@ -102,7 +551,8 @@ impl DepletionState {
     pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")
 )]
 pub struct MarketResilienceIndicator {
-    /// The current resilience score
+    /// The current resilience score, combining the book-depletion and trade-recovery components
+    /// (see [`Self::recompute_score`]).
     pub score: f64,
     /// The bias side of the market
     pub bias_side: OrderSide,
@@ -131,13 +581,70 @@ pub struct MarketResilienceIndicator {
     pub same_side_bias: f64,
     pub opposite_side_bias: f64,
     
+    /// The notional (or contract) size to walk from the best price when detecting depletion.
+    /// When set, depletion is detected from an "impact price" reached by accumulating level
+    /// size outward from the top of book, rather than from a fixed level count.
+    pub impact_notional: Option<f64>,
+
+    /// The decay factor for the online spread mean/variance. When set, spread shocks are
+    /// flagged from a z-score against this EWMA instead of the flat `spread_increase_threshold`
+    /// percentage over the window mean.
+    pub ewma_alpha: Option<f64>,
+    /// The z-score above which a spread move is flagged as a shock, when `ewma_alpha` is set.
+    pub ewma_k: f64,
+    ewma_mean: f64,
+    ewma_var: f64,
+    ewma_initialized: bool,
+
+    /// The target quantile (e.g. `0.9`) of recent spreads above which a move is flagged as a
+    /// shock. When set, this replaces both `spread_increase_threshold` and `ewma_alpha` as the
+    /// shock trigger, self-tuning to each instrument's normal spread regime via the P² algorithm
+    /// instead of a hand-tuned multiplier.
+    pub quantile_threshold: Option<f64>,
+    quantile_estimator: Option<P2QuantileEstimator>,
+
     recent_spreads: VecDeque<f64>,
     depletion_state: DepletionState,
     previous_book: Option<OrderBook>,
+    /// The best bid/ask of the last quote tick processed, for lightweight quote-tick mode.
+    previous_quote_bid: Option<Price>,
+    previous_quote_ask: Option<Price>,
     pub is_spread_recovered: bool,
     pub is_strong_reversal: bool,
     pub is_depletion_continuing: bool,
-    pub bias_side: OrderSide,
+
+    /// The fraction of top-of-book depth a trade's size must exceed to open a trade-driven
+    /// recovery measurement.
+    pub trade_size_fraction: f64,
+    /// The fraction above a consumption event's baseline spread that still counts as recovered.
+    pub trade_spread_tolerance: f64,
+    /// The weight of the median trade-recovery score folded into `score`; `0.0` (the default)
+    /// leaves `score` driven solely by book-based depletion.
+    pub trade_weight: f64,
+    /// The median trade-recovery score over `trade_recovery_times`, normalized against `timeout`.
+    pub trade_recovery_score: f64,
+    /// The book-depletion-only component of `score`, before the trade-recovery term is blended
+    /// in by [`Self::recompute_score`]. Kept separate so the two update paths (book-depletion
+    /// recovery and trade-driven recovery) combine into a single well-defined `score` instead of
+    /// each mutating it independently.
+    resilience_score: f64,
+    open_trade_event: Option<TradeConsumptionEvent>,
+    trade_recovery_times: VecDeque<f64>,
+
+    /// Computes the final resilience score from the recovery metrics. Defaults to a
+    /// [`LinearScoreAdapter`] built from `time_weight`/`depth_weight`/`spread_weight` and the
+    /// side biases; swap in a different adapter via [`Self::with_score_adapter`].
+    score_adapter: Box<dyn ResilienceScoreAdapter>,
+
+    /// Converts level price/quantity into quote-currency notional for the depth-recovery
+    /// component of the score. Defaults to a [`LinearAsset`] with `contract_size = 1.0`; swap in
+    /// a different contract via [`Self::with_asset_type`].
+    asset_type: Box<dyn AssetType>,
+
+    /// A secondary AMM liquidity source folded into depth-recovery notional alongside the
+    /// discrete order book, for hybrid venues. `None` (the default) considers only the book.
+    /// Set via [`Self::with_virtual_amm_pool`].
+    virtual_amm: Option<VirtualAmmPool>,
 }
 
 impl Display for MarketResilienceIndicator {
@@ -170,7 +677,22 @@ impl MarketResilienceIndicator {
     /// - `spread_weight`: The weight for spread recovery in the resilience score.
     /// - `same_side_bias`: The bias adjustment when recovery is on the same side as depletion.
     /// - `opposite_side_bias`: The bias adjustment when recovery is on the opposite side of depletion.
+    /// - `impact_notional`: When set, switches depletion detection to an impact-size walk of this
+    ///   notional rather than the fixed `levels_to_consume` level count.
+    /// - `ewma_alpha`: When set, switches the spread-shock trigger to an online EWMA z-score
+    ///   with this decay factor, rather than the flat `spread_increase_threshold` over the window mean.
+    /// - `ewma_k`: The z-score threshold above which a spread move is flagged, when `ewma_alpha` is set.
+    /// - `trade_size_fraction`: The fraction of top-of-book depth a trade's size must exceed to
+    ///   open a trade-driven recovery measurement.
+    /// - `trade_spread_tolerance`: The fraction above a consumption event's baseline spread that
+    ///   still counts as recovered.
+    /// - `trade_weight`: The weight of the median trade-recovery score folded into `score`; `0.0`
+    ///   (the default) leaves `score` driven solely by book-based depletion.
+    /// - `quantile_threshold`: When set, switches the spread-shock trigger to an online P²
+    ///   estimate of this quantile of recent spreads, rather than `ewma_alpha` or the flat
+    ///   `spread_increase_threshold`.
     #[must_use]
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         timeout_ms: Option<u64>,
         spread_window_size: Option<usize>,
@@ -183,6 +705,13 @@ impl MarketResilienceIndicator {
         spread_weight: Option<f64>,
         same_side_bias: Option<f64>,
         opposite_side_bias: Option<f64>,
+        impact_notional: Option<f64>,
+        ewma_alpha: Option<f64>,
+        ewma_k: Option<f64>,
+        trade_size_fraction: Option<f64>,
+        trade_spread_tolerance: Option<f64>,
+        trade_weight: Option<f64>,
+        quantile_threshold: Option<f64>,
     ) -> Self {
         Self {
             score: 0.0,
@@ -204,17 +733,275 @@ impl MarketResilienceIndicator {
             spread_weight: spread_weight.unwrap_or(0.5),
             same_side_bias: same_side_bias.unwrap_or(0.5),
             opposite_side_bias: opposite_side_bias.unwrap_or(-0.5),
+            impact_notional,
+            ewma_alpha,
+            ewma_k: ewma_k.unwrap_or(2.0),
+            ewma_mean: 0.0,
+            ewma_var: 0.0,
+            ewma_initialized: false,
+            quantile_estimator: quantile_threshold.map(P2QuantileEstimator::new),
+            quantile_threshold,
             recent_spreads: VecDeque::with_capacity(spread_window_size.unwrap_or(50)),
             depletion_state: DepletionState::new(UnixNanos::from(timeout_ms.unwrap_or(500) * 1_000_000)),
             previous_book: None,
+            previous_quote_bid: None,
+            previous_quote_ask: None,
             is_spread_recovered: false,
             is_strong_reversal: false,
             is_depletion_continuing: false,
-            bias_side: OrderSide::NoOrderSide,
+            trade_size_fraction: trade_size_fraction.unwrap_or(0.5),
+            trade_spread_tolerance: trade_spread_tolerance.unwrap_or(0.1),
+            trade_weight: trade_weight.unwrap_or(0.0),
+            trade_recovery_score: 0.0,
+            resilience_score: 0.0,
+            open_trade_event: None,
+            trade_recovery_times: VecDeque::with_capacity(spread_window_size.unwrap_or(50)),
+            score_adapter: Box::new(LinearScoreAdapter {
+                time_weight: time_weight.unwrap_or(0.5),
+                depth_weight: depth_weight.unwrap_or(0.0),
+                spread_weight: spread_weight.unwrap_or(0.5),
+                same_side_bias: same_side_bias.unwrap_or(0.5),
+                opposite_side_bias: opposite_side_bias.unwrap_or(-0.5),
+            }),
+            asset_type: Box::new(LinearAsset { contract_size: 1.0 }),
+            virtual_amm: None,
+        }
+    }
+
+    /// Replaces the indicator's [`ResilienceScoreAdapter`], letting callers swap in custom
+    /// scoring logic without forking the indicator.
+    #[must_use]
+    pub fn with_score_adapter(mut self, adapter: Box<dyn ResilienceScoreAdapter>) -> Self {
+        self.score_adapter = adapter;
+        self
+    }
+
+    /// Replaces the indicator's [`AssetType`], letting depth-recovery notional be computed for
+    /// inverse (coin-margined) contracts instead of the default linear convention.
+    #[must_use]
+    pub fn with_asset_type(mut self, asset_type: Box<dyn AssetType>) -> Self {
+        self.asset_type = asset_type;
+        self
+    }
+
+    /// Adds a [`VirtualAmmPool`] as a secondary liquidity source, so depth-recovery notional
+    /// reflects total executable liquidity on a hybrid CLOB/AMM venue rather than only the book.
+    #[must_use]
+    pub fn with_virtual_amm_pool(mut self, pool: VirtualAmmPool) -> Self {
+        self.virtual_amm = Some(pool);
+        self
+    }
+
+    /// Captures a serializable snapshot of the indicator's current state, for checkpointing a
+    /// live session or a resumable backtest without replaying history.
+    #[must_use]
+    pub fn to_state(&self) -> ResilienceIndicatorState {
+        ResilienceIndicatorState {
+            score: self.score,
+            bias_side: self.bias_side,
+            depletion_side: self.depletion_side,
+            recovery_side: self.recovery_side,
+            recovery_time: self.recovery_time,
+            count: self.count,
+            initialized: self.initialized,
+            has_inputs: self.has_inputs,
+            timeout: self.timeout,
+            spread_window_size: self.spread_window_size,
+            levels_to_consume: self.levels_to_consume,
+            spread_increase_threshold: self.spread_increase_threshold,
+            strong_resilience_threshold: self.strong_resilience_threshold,
+            weak_resilience_threshold: self.weak_resilience_threshold,
+            time_weight: self.time_weight,
+            depth_weight: self.depth_weight,
+            spread_weight: self.spread_weight,
+            same_side_bias: self.same_side_bias,
+            opposite_side_bias: self.opposite_side_bias,
+            impact_notional: self.impact_notional,
+            ewma_alpha: self.ewma_alpha,
+            ewma_k: self.ewma_k,
+            trade_size_fraction: self.trade_size_fraction,
+            trade_spread_tolerance: self.trade_spread_tolerance,
+            trade_weight: self.trade_weight,
+            quantile_threshold: self.quantile_threshold,
+            is_spread_recovered: self.is_spread_recovered,
+            is_strong_reversal: self.is_strong_reversal,
+            is_depletion_continuing: self.is_depletion_continuing,
+            recent_spreads: self.recent_spreads.iter().copied().collect(),
+            ewma_mean: self.ewma_mean,
+            ewma_var: self.ewma_var,
+            ewma_initialized: self.ewma_initialized,
+            monitor_depletion_side: self.depletion_state.depletion_side,
+            monitor_recovery_side: self.depletion_state.recovery_side,
+            monitor_initial_price: self.depletion_state.initial_price,
+            monitor_start_time: self.depletion_state.start_time,
+            monitor_end_time: self.depletion_state.end_time,
+            monitor_initial_spread: self.depletion_state.initial_book.and_then(|s| s.spread),
+            monitor_initial_depth: self.depletion_state.initial_book.map(|s| s.depth),
+            monitor_end_spread: self.depletion_state.end_book.and_then(|s| s.spread),
+            monitor_end_depth: self.depletion_state.end_book.map(|s| s.depth),
+            previous_best_bid: self
+                .previous_book
+                .as_ref()
+                .and_then(OrderBook::best_bid_price)
+                .map(|p| p.as_f64()),
+            previous_best_ask: self
+                .previous_book
+                .as_ref()
+                .and_then(OrderBook::best_ask_price)
+                .map(|p| p.as_f64()),
+            previous_spread: self.previous_book.as_ref().and_then(OrderBook::spread),
+            previous_ts_last: self.previous_book.as_ref().map(|b| b.ts_last),
+            trade_recovery_score: self.trade_recovery_score,
+            resilience_score: self.resilience_score,
+            trade_recovery_times: self.trade_recovery_times.iter().copied().collect(),
+            open_trade_event_ts: self.open_trade_event.map(|event| event.ts),
+            open_trade_event_side: self
+                .open_trade_event
+                .map_or(OrderSide::NoOrderSide, |event| event.depletion_side),
+            open_trade_event_baseline_spread: self.open_trade_event.map(|event| event.baseline_spread),
+            quantile_estimator: self.quantile_estimator,
         }
     }
 
+    /// Restores configuration and runtime state captured by [`Self::to_state`], letting a live
+    /// session or backtest resume without replaying history or being reconstructed with matching
+    /// arguments first.
+    ///
+    /// Because the restored state does not carry the full set of price levels for the previous
+    /// book, depletion detection resumes from the next book the indicator receives -- the same
+    /// behavior a freshly constructed indicator has on its first update. A custom
+    /// [`ResilienceScoreAdapter`] installed via [`Self::with_score_adapter`] is not captured by
+    /// `to_state`; `from_state` rebuilds a default [`LinearScoreAdapter`] from the restored
+    /// weight/bias fields so scoring actually uses the saved weights, which replaces any custom
+    /// adapter -- re-install it with [`Self::with_score_adapter`] after calling `from_state` if
+    /// one was in use. The restored `initial_book`/`end_book` snapshots are always treated as
+    /// full (non-partial) depth, since `to_state` does not persist whether a snapshot came from
+    /// `handle_quote_tick`.
+    pub fn from_state(&mut self, state: ResilienceIndicatorState) {
+        self.timeout = state.timeout;
+        self.spread_window_size = state.spread_window_size;
+        self.levels_to_consume = state.levels_to_consume;
+        self.spread_increase_threshold = state.spread_increase_threshold;
+        self.strong_resilience_threshold = state.strong_resilience_threshold;
+        self.weak_resilience_threshold = state.weak_resilience_threshold;
+        self.time_weight = state.time_weight;
+        self.depth_weight = state.depth_weight;
+        self.spread_weight = state.spread_weight;
+        self.same_side_bias = state.same_side_bias;
+        self.opposite_side_bias = state.opposite_side_bias;
+        self.score_adapter = Box::new(LinearScoreAdapter {
+            time_weight: self.time_weight,
+            depth_weight: self.depth_weight,
+            spread_weight: self.spread_weight,
+            same_side_bias: self.same_side_bias,
+            opposite_side_bias: self.opposite_side_bias,
+        });
+        self.impact_notional = state.impact_notional;
+        self.ewma_alpha = state.ewma_alpha;
+        self.ewma_k = state.ewma_k;
+        self.trade_size_fraction = state.trade_size_fraction;
+        self.trade_spread_tolerance = state.trade_spread_tolerance;
+        self.trade_weight = state.trade_weight;
+        self.quantile_threshold = state.quantile_threshold;
+        self.quantile_estimator = state.quantile_estimator;
+        self.score = state.score;
+        self.bias_side = state.bias_side;
+        self.depletion_side = state.depletion_side;
+        self.recovery_side = state.recovery_side;
+        self.recovery_time = state.recovery_time;
+        self.count = state.count;
+        self.initialized = state.initialized;
+        self.has_inputs = state.has_inputs;
+        self.is_spread_recovered = state.is_spread_recovered;
+        self.is_strong_reversal = state.is_strong_reversal;
+        self.is_depletion_continuing = state.is_depletion_continuing;
+        self.recent_spreads = state.recent_spreads.into_iter().collect();
+        self.ewma_mean = state.ewma_mean;
+        self.ewma_var = state.ewma_var;
+        self.ewma_initialized = state.ewma_initialized;
+        self.depletion_state.depletion_side = state.monitor_depletion_side;
+        self.depletion_state.recovery_side = state.monitor_recovery_side;
+        self.depletion_state.initial_price = state.monitor_initial_price;
+        self.depletion_state.start_time = state.monitor_start_time;
+        self.depletion_state.end_time = state.monitor_end_time;
+        self.depletion_state.initial_book =
+            state
+                .monitor_initial_depth
+                .map(|depth| BookSnapshot {
+                    spread: state.monitor_initial_spread,
+                    depth,
+                    is_partial: false,
+                    ts_last: state.monitor_start_time.unwrap_or_default(),
+                });
+        self.depletion_state.end_book = state.monitor_end_depth.map(|depth| BookSnapshot {
+            spread: state.monitor_end_spread,
+            depth,
+            is_partial: false,
+            ts_last: state.monitor_end_time.unwrap_or_default(),
+        });
+        self.previous_book = None;
+        self.trade_recovery_score = state.trade_recovery_score;
+        self.resilience_score = state.resilience_score;
+        self.trade_recovery_times = state.trade_recovery_times.into_iter().collect();
+        self.open_trade_event = state.open_trade_event_ts.map(|ts| TradeConsumptionEvent {
+            ts,
+            depletion_side: state.open_trade_event_side,
+            baseline_spread: state.open_trade_event_baseline_spread.unwrap_or(0.0),
+        });
+    }
+
     fn detect_depletion(&self, previous: &OrderBook, current: &OrderBook) -> Option<(OrderSide, Price)> {
+        if let Some(impact_notional) = self.impact_notional {
+            return self.detect_depletion_by_impact(previous, current, impact_notional);
+        }
+        self.detect_depletion_by_levels(previous, current)
+    }
+
+    /// Walks `levels` outward from the best price, accumulating size until it reaches
+    /// `impact_notional`, and returns that level's price. Falls back to the deepest available
+    /// level if the whole side has less cumulative size than `impact_notional`.
+    fn walk_impact_price<'a>(
+        levels: impl Iterator<Item = &'a BookLevel>,
+        impact_notional: f64,
+    ) -> Option<Price> {
+        let mut cumulative = 0.0;
+        let mut last_price = None;
+        for level in levels {
+            cumulative += level.size();
+            last_price = Some(level.price.value);
+            if cumulative >= impact_notional {
+                break;
+            }
+        }
+        last_price
+    }
+
+    fn detect_depletion_by_impact(
+        &self,
+        previous: &OrderBook,
+        current: &OrderBook,
+        impact_notional: f64,
+    ) -> Option<(OrderSide, Price)> {
+        if let Some(bid_impact_price) = Self::walk_impact_price(previous.bids(), impact_notional) {
+            if let Some(current_best_bid) = current.best_bid_price() {
+                if current_best_bid < bid_impact_price {
+                    return Some((OrderSide::Buy, bid_impact_price));
+                }
+            }
+        }
+
+        if let Some(ask_impact_price) = Self::walk_impact_price(previous.asks(), impact_notional) {
+            if let Some(current_best_ask) = current.best_ask_price() {
+                if current_best_ask > ask_impact_price {
+                    return Some((OrderSide::Sell, ask_impact_price));
+                }
+            }
+        }
+
+        None
+    }
+
+    fn detect_depletion_by_levels(&self, previous: &OrderBook, current: &OrderBook) -> Option<(OrderSide, Price)> {
         // Check for bid side depletion
         if let Some(prev_bid_threshold) = previous.bids().nth(self.levels_to_consume - 1) {
             let threshold_price = prev_bid_threshold.price.value;
@@ -238,35 +1025,125 @@ impl MarketResilienceIndicator {
         None
     }
 
-    fn is_spread_increased(&self, book: &OrderBook) -> bool {
+    /// Detects depletion from a single quote-tick level, since no deeper levels are known: the
+    /// bid side is depleted if it fell below the previous bid, the ask side if it rose above the
+    /// previous ask.
+    fn detect_quote_depletion(
+        prev_bid: Price,
+        prev_ask: Price,
+        bid: Price,
+        ask: Price,
+    ) -> Option<(OrderSide, Price)> {
+        if bid < prev_bid {
+            return Some((OrderSide::Buy, prev_bid));
+        }
+        if ask > prev_ask {
+            return Some((OrderSide::Sell, prev_ask));
+        }
+        None
+    }
+
+    /// Computes the depth-recovery notional for a single quote-tick level. The returned snapshot
+    /// is marked [`BookSnapshot::is_partial`], which is what actually down-weights the depth term
+    /// in [`Self::calculate_normalized_metrics`] -- scaling this single-level notional itself
+    /// would have no effect, since `depth_recovery` is a ratio of two snapshots and any constant
+    /// factor applied equally to both cancels out.
+    fn quote_depth(asset_type: &dyn AssetType, bid: Price, ask: Price, bid_size: f64, ask_size: f64) -> f64 {
+        let bid_notional = asset_type.notional(bid.as_f64(), bid_size).unwrap_or(0.0);
+        let ask_notional = asset_type.notional(ask.as_f64(), ask_size).unwrap_or(0.0);
+        bid_notional + ask_notional
+    }
+
+    /// Computes the mean of `recent_spreads` in fixed-point so the result is bit-reproducible
+    /// regardless of summation order across platforms.
+    fn fixed_average_spread(&self) -> I80F48 {
+        let sum = self
+            .recent_spreads
+            .iter()
+            .fold(I80F48::ZERO, |acc, &spread| acc + fx(spread));
+        sum / fx(self.recent_spreads.len() as f64)
+    }
+
+    /// Updates the online EWMA mean/variance of the spread with a new observation.
+    fn update_ewma(&mut self, spread: f64, alpha: f64) {
+        if !self.ewma_initialized {
+            self.ewma_mean = spread;
+            self.ewma_var = 0.0;
+            self.ewma_initialized = true;
+            return;
+        }
+
+        let diff = spread - self.ewma_mean;
+        self.ewma_mean += alpha * diff;
+        self.ewma_var = (1.0 - alpha) * (self.ewma_var + alpha * diff * diff);
+    }
+
+    /// Returns the z-score of `spread` against the online EWMA mean/variance, or `None` while
+    /// the variance is not yet positive (e.g. the first observation).
+    fn ewma_z_score(&self, spread: f64) -> Option<f64> {
+        if !self.ewma_initialized || self.ewma_var <= 0.0 {
+            return None;
+        }
+        Some((spread - self.ewma_mean) / self.ewma_var.sqrt())
+    }
+
+    /// Returns `true` if `spread` indicates a significant widening, from the P² quantile estimate
+    /// (when `quantile_threshold` is set), else the online EWMA z-score (when `ewma_alpha` is
+    /// set), else the flat `spread_increase_threshold` over the window mean. Takes a plain `Option<f64>` rather than an `&OrderBook` so it can be shared between
+    /// the book and quote-tick input paths.
+    fn is_spread_increased(&self, spread: Option<f64>) -> bool {
+        if self.quantile_threshold.is_some() {
+            return match (spread, self.quantile_estimator.as_ref().and_then(P2QuantileEstimator::quantile)) {
+                (Some(spread), Some(q)) => spread > q,
+                _ => false,
+            };
+        }
+
+        if let Some(spread) = spread {
+            if self.ewma_alpha.is_some() {
+                return self
+                    .ewma_z_score(spread)
+                    .map(|z| z > self.ewma_k)
+                    .unwrap_or(false);
+            }
+        }
+
         if self.recent_spreads.is_empty() {
             return false;
         }
 
-        let avg_spread = self.recent_spreads.iter().sum::<f64>() / self.recent_spreads.len() as f64;
-        book.spread()
-            .map(|spread| spread > avg_spread * (1.0 + self.spread_increase_threshold))
-            .unwrap_or(false)
+        let avg_spread = self.fixed_average_spread();
+        let threshold = avg_spread * (I80F48::ONE + fx(self.spread_increase_threshold));
+        spread.map(|spread| fx(spread) > threshold).unwrap_or(false)
     }
 
-    fn is_spread_back_to_average(&self, book: &OrderBook) -> bool {
+    /// Returns `true` if `spread` has reverted back towards the window average. See
+    /// [`Self::is_spread_increased`] for why this takes a plain `Option<f64>`.
+    fn is_spread_back_to_average(&self, spread: Option<f64>) -> bool {
+        if let Some(spread) = spread {
+            if self.ewma_alpha.is_some() {
+                return self.ewma_z_score(spread).map(|z| z <= 0.0).unwrap_or(false);
+            }
+        }
+
         if self.recent_spreads.is_empty() {
             return false;
         }
 
-        let avg_spread = self.recent_spreads.iter().sum::<f64>() / self.recent_spreads.len() as f64;
-        book.spread()
-            .map(|spread| spread <= avg_spread)
-            .unwrap_or(false)
+        let avg_spread = self.fixed_average_spread();
+        spread.map(|spread| fx(spread) <= avg_spread).unwrap_or(false)
     }
 
-    fn get_book_recovery_side(&self, current: &OrderBook) -> OrderSide {
+    /// Determines the recovery side from the current best bid/ask relative to the depletion
+    /// window's initial price. Takes plain `Option<Price>` best bid/ask rather than an
+    /// `&OrderBook` so it can be shared between the book and quote-tick input paths.
+    fn get_recovery_side(&self, best_bid: Option<Price>, best_ask: Option<Price>) -> OrderSide {
         let initial_price = self.depletion_state.initial_price;
         let depletion_side = self.depletion_state.depletion_side;
 
         match depletion_side {
             OrderSide::Buy => {
-                if let Some(best_bid) = current.best_bid_price() {
+                if let Some(best_bid) = best_bid {
                     if best_bid >= initial_price {
                         OrderSide::Buy
                     } else {
@@ -277,7 +1154,7 @@ impl MarketResilienceIndicator {
                 }
             }
             OrderSide::Sell => {
-                if let Some(best_ask) = current.best_ask_price() {
+                if let Some(best_ask) = best_ask {
                     if best_ask <= initial_price {
                         OrderSide::Sell
                     } else {
@@ -299,16 +1176,18 @@ impl MarketResilienceIndicator {
         let initial_book = self.depletion_state.initial_book.as_ref().unwrap();
         let end_book = self.depletion_state.end_book.as_ref().unwrap();
 
-        let (normalized_time, spread_recovery, depth_recovery) = 
+        let (normalized_time, spread_recovery, depth_recovery) =
             self.calculate_normalized_metrics(initial_book, end_book, recovery_time);
 
-        let base_score = self.time_weight * normalized_time
-            + self.depth_weight * depth_recovery
-            + self.spread_weight * spread_recovery;
-
         let has_recovered_same_side = depletion_side == recovery_side;
-        let bias_score = if has_recovered_same_side { self.same_side_bias_side } else { self.opposite_side_bias_side };
-        self.score = (base_score + bias_score).max(0.0).min(1.0);
+        let score = self.score_adapter.compute(
+            normalized_time.to_num::<f64>(),
+            spread_recovery.to_num::<f64>(),
+            depth_recovery.to_num::<f64>(),
+            has_recovered_same_side,
+        );
+        self.resilience_score = score.clamp(0.0, 1.0);
+        self.recompute_score();
 
         self.is_spread_recovered = true;
         self.bias_side = recovery_side;
@@ -321,35 +1200,48 @@ impl MarketResilienceIndicator {
         self.recovery_time = recovery_time;
     }
 
+    /// Computes the normalized time/spread/depth recovery components in fixed-point so the
+    /// accumulated resilience score is immune to floating-point summation-order drift.
     fn calculate_normalized_metrics(
         &self,
-        initial_book: &OrderBook,
-        end_book: &OrderBook,
+        initial_book: &BookSnapshot,
+        end_book: &BookSnapshot,
         recovery_time: UnixNanos,
-    ) -> (f64, f64, f64) {
+    ) -> (I80F48, I80F48, I80F48) {
         // Normalized time recovery
-        let normalized_time = 1.0 - (recovery_time.as_f64() / self.timeout.as_f64());
-        let normalized_time = normalized_time.max(0.0);
+        let normalized_time = (I80F48::ONE - (fx(recovery_time.as_f64()) / fx(self.timeout.as_f64())))
+            .max(I80F48::ZERO);
 
         // Normalized spread recovery
-        let spread_recovery = initial_book.spread()
-            .zip(end_book.spread())
+        let spread_recovery = initial_book
+            .spread
+            .zip(end_book.spread)
             .map(|(initial, end)| {
                 if initial > 0.0 {
-                    ((initial - end) / initial).max(0.0).min(1.0)
+                    ((fx(initial) - fx(end)) / fx(initial)).clamp(I80F48::ZERO, I80F48::ONE)
                 } else {
-                    0.0
+                    I80F48::ZERO
                 }
             })
-            .unwrap_or(0.0);
+            .unwrap_or(I80F48::ZERO);
 
         // Normalized depth recovery
-        let initial_depth = (initial_book.bids().count() + initial_book.asks().count()) as f64;
-        let end_depth = (end_book.bids().count() + end_book.asks().count()) as f64;
+        let initial_depth = initial_book.depth;
+        let end_depth = end_book.depth;
         let depth_recovery = if initial_depth > 0.0 {
-            (end_depth / initial_depth).max(0.0).min(1.0)
+            (fx(end_depth) / fx(initial_depth)).clamp(I80F48::ZERO, I80F48::ONE)
         } else {
-            0.0
+            I80F48::ZERO
+        };
+
+        // When either snapshot came from a quote tick (single level, not a full book), blend the
+        // ratio toward the neutral midpoint so the depth term actually carries less weight in
+        // [`ResilienceScoreAdapter::compute`] -- scaling `initial_depth`/`end_depth` themselves
+        // would cancel out in the ratio above and have no effect (see [`Self::quote_depth`]).
+        let depth_recovery = if initial_book.is_partial || end_book.is_partial {
+            depth_recovery * fx(QUOTE_DEPTH_DISCOUNT) + fx(0.5) * (I80F48::ONE - fx(QUOTE_DEPTH_DISCOUNT))
+        } else {
+            depth_recovery
         };
 
         (normalized_time, spread_recovery, depth_recovery)
@@ -359,6 +1251,61 @@ impl MarketResilienceIndicator {
         self.depletion_state.reset();
         self.previous_book = None;
     }
+
+    /// Recomputes `score` as a single well-defined combination of the book-depletion component
+    /// (`resilience_score`) and the trade-recovery component (`trade_recovery_score`), rather
+    /// than letting the two update paths mutate `score` independently of one another.
+    fn recompute_score(&mut self) {
+        self.score = (self.resilience_score + self.trade_weight * self.trade_recovery_score).clamp(0.0, 1.0);
+    }
+
+    /// Closes the open trade-consumption event, recording `recovery_ms` into the rolling window
+    /// and folding the updated median trade-recovery score into `score` via [`Self::recompute_score`].
+    fn close_trade_event(&mut self, recovery_ms: f64) {
+        self.trade_recovery_times.push_back(recovery_ms);
+        if self.trade_recovery_times.len() > self.spread_window_size {
+            self.trade_recovery_times.pop_front();
+        }
+        self.open_trade_event = None;
+
+        let Some(median_ms) = median(self.trade_recovery_times.iter().copied()) else {
+            return;
+        };
+        let timeout_ms = self.timeout.as_f64() / 1_000_000.0;
+        let normalized = if timeout_ms > 0.0 {
+            (1.0 - median_ms / timeout_ms).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        self.trade_recovery_score = normalized;
+        self.recompute_score();
+    }
+
+    /// Checks the open trade-consumption event (if any) against the latest `spread`/`ts`,
+    /// closing it once the spread has returned within tolerance of the event's baseline, or once
+    /// it has been open longer than `timeout`, in which case it is recorded as a worst-case
+    /// recovery. Takes plain `Option<f64>`/`UnixNanos` rather than an `&OrderBook` so it can be
+    /// shared between the book and quote-tick input paths.
+    fn check_trade_recovery(&mut self, spread: Option<f64>, ts: UnixNanos) {
+        let Some(event) = self.open_trade_event else {
+            return;
+        };
+
+        let elapsed_ns = (ts - event.ts).as_f64();
+        let elapsed_ms = elapsed_ns / 1_000_000.0;
+
+        if let Some(spread) = spread {
+            if spread <= event.baseline_spread * (1.0 + self.trade_spread_tolerance) {
+                self.close_trade_event(elapsed_ms);
+                return;
+            }
+        }
+
+        if elapsed_ns > self.timeout.as_f64() {
+            let timeout_ms = self.timeout.as_f64() / 1_000_000.0;
+            self.close_trade_event(timeout_ms);
+        }
+    }
 }
 
 impl Indicator for MarketResilienceIndicator {
@@ -382,16 +1329,25 @@ impl Indicator for MarketResilienceIndicator {
         self.has_inputs = true;
         self.count += 1;
 
+        self.check_trade_recovery(book.spread(), book.ts_last);
+
         if self.depletion_state.is_running() {
-            if self.is_spread_back_to_average(book) {
-                let recovery_side = self.get_book_recovery_side(book);
+            if self.is_spread_back_to_average(book.spread()) {
+                let recovery_side = self.get_recovery_side(book.best_bid_price(), book.best_ask_price());
                 if recovery_side != OrderSide::NoOrderSide {
-                    self.depletion_state.set_end(book.clone(), recovery_side);
+                    self.depletion_state.set_end(
+                        book,
+                        recovery_side,
+                        self.asset_type.as_ref(),
+                        self.virtual_amm.as_ref(),
+                        self.levels_to_consume,
+                    );
                     self.calculate_resilience_metrics();
                     self.reset_monitoring();
                 }
             } else if self.depletion_state.is_timeout(book.ts_last) {
-                self.score = 0.0;
+                self.resilience_score = 0.0;
+                self.recompute_score();
                 self.bias_side = OrderSide::NoOrderSide;
                 self.depletion_side = self.depletion_state.depletion_side;
                 self.recovery_side = OrderSide::NoOrderSide;
@@ -405,13 +1361,26 @@ impl Indicator for MarketResilienceIndicator {
                 if self.recent_spreads.len() > self.spread_window_size {
                     self.recent_spreads.pop_front();
                 }
+                if let Some(alpha) = self.ewma_alpha {
+                    self.update_ewma(spread, alpha);
+                }
+                if let Some(estimator) = self.quantile_estimator.as_mut() {
+                    estimator.update(spread);
+                }
             }
 
             // Check for depletion
             if let Some(previous) = &self.previous_book {
                 if let Some((side, price)) = self.detect_depletion(previous, book) {
-                    if self.is_spread_increased(book) {
-                        self.depletion_state.set_initial(book.clone(), side, price);
+                    if self.is_spread_increased(book.spread()) {
+                        self.depletion_state.set_initial(
+                            book,
+                            side,
+                            price,
+                            self.asset_type.as_ref(),
+                            self.virtual_amm.as_ref(),
+                            self.levels_to_consume,
+                        );
                     }
                 }
             }
@@ -422,33 +1391,165 @@ impl Indicator for MarketResilienceIndicator {
         self.initialized = true;
     }
 
-    fn reset(&mut self) {
-        self.score = 0.0;
+    /// Updates the indicator from a best bid/ask quote tick rather than a full order book
+    /// snapshot, for venues where subscribing to `OrderBook` deltas is too expensive. Since only
+    /// the top of book is known, the resulting snapshots are marked partial (see
+    /// [`Self::quote_depth`]), which blends their depth-recovery contribution toward a neutral
+    /// midpoint in [`Self::calculate_normalized_metrics`], so `score` is driven mostly by
+    /// `time_weight` and `spread_weight`.
+    fn handle_quote_tick(&mut self, quote: &QuoteTick) {
         self.is_spread_recovered = false;
         self.is_strong_reversal = false;
         self.is_depletion_continuing = false;
-        self.bias_side = OrderSide::NoOrderSide;
-        self.depletion_side = OrderSide::NoOrderSide;
-        self.recovery_side = OrderSide::NoOrderSide;
-        self.recovery_time = self.timeout;
-        self.count = 0;
-        self.initialized = false;
-        self.has_inputs = false;
-        self.recent_spreads.clear();
-        self.depletion_state.reset();
-        self.previous_book = None;
-    }
-}
 
-////////////////////////////////////////////////////////////////////////////////
-// Tests
-////////////////////////////////////////////////////////////////////////////////
-#[cfg(test)]
-mod tests {
+        self.has_inputs = true;
+        self.count += 1;
+
+        let bid = quote.bid_price;
+        let ask = quote.ask_price;
+        let bid_size = quote.bid_size.as_f64();
+        let ask_size = quote.ask_size.as_f64();
+        let ts = quote.ts_event;
+        let spread = ask.as_f64() - bid.as_f64();
+
+        self.check_trade_recovery(Some(spread), ts);
+
+        if self.depletion_state.is_running() {
+            if self.is_spread_back_to_average(Some(spread)) {
+                let recovery_side = self.get_recovery_side(Some(bid), Some(ask));
+                if recovery_side != OrderSide::NoOrderSide {
+                    let depth = Self::quote_depth(self.asset_type.as_ref(), bid, ask, bid_size, ask_size);
+                    self.depletion_state.set_end_snapshot(
+                        BookSnapshot {
+                            spread: Some(spread),
+                            depth,
+                            is_partial: true,
+                            ts_last: ts,
+                        },
+                        recovery_side,
+                        ts,
+                    );
+                    self.calculate_resilience_metrics();
+                    self.reset_monitoring();
+                }
+            } else if self.depletion_state.is_timeout(ts) {
+                self.resilience_score = 0.0;
+                self.recompute_score();
+                self.bias_side = OrderSide::NoOrderSide;
+                self.depletion_side = self.depletion_state.depletion_side;
+                self.recovery_side = OrderSide::NoOrderSide;
+                self.recovery_time = self.timeout;
+                self.reset_monitoring();
+            }
+        } else {
+            self.recent_spreads.push_back(spread);
+            if self.recent_spreads.len() > self.spread_window_size {
+                self.recent_spreads.pop_front();
+            }
+            if let Some(alpha) = self.ewma_alpha {
+                self.update_ewma(spread, alpha);
+            }
+            if let Some(estimator) = self.quantile_estimator.as_mut() {
+                estimator.update(spread);
+            }
+
+            if let (Some(prev_bid), Some(prev_ask)) = (self.previous_quote_bid, self.previous_quote_ask) {
+                if let Some((side, price)) = Self::detect_quote_depletion(prev_bid, prev_ask, bid, ask) {
+                    if self.is_spread_increased(Some(spread)) {
+                        let depth = Self::quote_depth(self.asset_type.as_ref(), bid, ask, bid_size, ask_size);
+                        self.depletion_state.set_initial_snapshot(
+                            BookSnapshot {
+                                spread: Some(spread),
+                                depth,
+                                is_partial: true,
+                                ts_last: ts,
+                            },
+                            side,
+                            price,
+                            ts,
+                        );
+                    }
+                }
+            }
+
+            self.previous_quote_bid = Some(bid);
+            self.previous_quote_ask = Some(ask);
+        }
+
+        self.initialized = true;
+    }
+
+    fn handle_trade_tick(&mut self, trade: &TradeTick) {
+        let Some(book) = &self.previous_book else {
+            return;
+        };
+
+        let top_of_book_size = match trade.aggressor_side {
+            AggressorSide::Buyer => book.asks().next().map(BookLevel::size),
+            AggressorSide::Seller => book.bids().next().map(BookLevel::size),
+        };
+        let Some(top_of_book_size) = top_of_book_size else {
+            return;
+        };
+        if top_of_book_size <= 0.0 || trade.size.as_f64() < top_of_book_size * self.trade_size_fraction {
+            return;
+        }
+
+        let Some(baseline_spread) = median(self.recent_spreads.iter().copied()) else {
+            return;
+        };
+
+        let depletion_side = match trade.aggressor_side {
+            AggressorSide::Buyer => OrderSide::Sell,
+            AggressorSide::Seller => OrderSide::Buy,
+        };
+
+        self.open_trade_event = Some(TradeConsumptionEvent {
+            ts: trade.ts_event,
+            depletion_side,
+            baseline_spread,
+        });
+        self.bias_side = depletion_side;
+    }
+
+    fn reset(&mut self) {
+        self.score = 0.0;
+        self.resilience_score = 0.0;
+        self.is_spread_recovered = false;
+        self.is_strong_reversal = false;
+        self.is_depletion_continuing = false;
+        self.bias_side = OrderSide::NoOrderSide;
+        self.depletion_side = OrderSide::NoOrderSide;
+        self.recovery_side = OrderSide::NoOrderSide;
+        self.recovery_time = self.timeout;
+        self.count = 0;
+        self.initialized = false;
+        self.has_inputs = false;
+        self.recent_spreads.clear();
+        self.ewma_mean = 0.0;
+        self.ewma_var = 0.0;
+        self.ewma_initialized = false;
+        self.depletion_state.reset();
+        self.previous_book = None;
+        self.previous_quote_bid = None;
+        self.previous_quote_ask = None;
+        self.trade_recovery_score = 0.0;
+        self.open_trade_event = None;
+        self.trade_recovery_times.clear();
+        self.quantile_estimator = self.quantile_threshold.map(P2QuantileEstimator::new);
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
     use super::*;
     use nautilus_model::{
         identifiers::InstrumentId,
-        stubs::stub_order_book_mbp,
+        stubs::{stub_order_book_mbp, stub_quote_tick_eth_usdt, stub_trade_tick_eth_usdt},
+        types::quantity::Quantity,
     };
     use rstest::rstest;
 
@@ -532,6 +1633,13 @@ mod tests {
             Some(0.5),
             Some(0.5),
             Some(-0.5),
+            None, // impact_notional
+            None, // ewma_alpha
+            None, // ewma_k
+            None, // trade_size_fraction
+            None, // trade_spread_tolerance
+            None, // trade_weight
+            None, // quantile_threshold
         );
         
         assert_eq!(indicator.name(), "MarketResilienceIndicator");
@@ -556,6 +1664,13 @@ mod tests {
             Some(0.5), // spread_weight
             Some(0.5), // same_side_bias
             Some(-0.5), // opposite_side_bias
+            None, // impact_notional
+            None, // ewma_alpha
+            None, // ewma_k
+            None, // trade_size_fraction
+            None, // trade_spread_tolerance
+            None, // trade_weight
+            None, // quantile_threshold
         );
 
         let mut book1 = create_test_book();
@@ -586,6 +1701,13 @@ mod tests {
             Some(0.5), // spread_weight
             Some(0.5), // same_side_bias
             Some(-0.5), // opposite_side_bias
+            None, // impact_notional
+            None, // ewma_alpha
+            None, // ewma_k
+            None, // trade_size_fraction
+            None, // trade_spread_tolerance
+            None, // trade_weight
+            None, // quantile_threshold
         );
 
         let mut book1 = create_test_book();
@@ -602,6 +1724,336 @@ mod tests {
         }
     }
 
+    #[rstest]
+    fn test_detect_depletion_by_impact_notional() {
+        let mut indicator = MarketResilienceIndicator::new(
+            Some(500), // 500ms
+            Some(50),  // spread_window_size
+            Some(3),   // levels_to_consume
+            Some(1.0), // spread_increase_threshold
+            Some(0.7), // strong_resilience_threshold
+            Some(0.3), // weak_resilience_threshold
+            Some(0.5), // time_weight
+            Some(0.0), // depth_weight
+            Some(0.5), // spread_weight
+            Some(0.5), // same_side_bias
+            Some(-0.5), // opposite_side_bias
+            Some(20.0), // impact_notional: walk two levels of size 10.0 each
+            None, // ewma_alpha
+            None, // ewma_k
+            None, // trade_size_fraction
+            None, // trade_spread_tolerance
+            None, // trade_weight
+            None, // quantile_threshold
+        );
+
+        let mut book1 = create_test_book();
+        add_test_orders(&mut book1, 10.0, 10.1, 10.0, 10.0, 0.1, 0.0, 3, 5, 0);
+
+        let mut book2 = create_test_book();
+        add_test_orders(&mut book2, 9.7, 10.1, 9.7, 10.0, 0.1, 0.0, 3, 5, 0);
+
+        // Impact price is the level reached after accumulating 20.0 of bid size (the 2nd level).
+        if let Some((side, price)) = indicator.detect_depletion(&book1, &book2) {
+            assert_eq!(side, OrderSide::Buy);
+            assert_eq!(price, Price::from("9.9"));
+        } else {
+            panic!("Should detect bid side depletion via impact-size walk");
+        }
+    }
+
+    #[rstest]
+    fn test_fixed_average_spread_matches_float_mean() {
+        let mut indicator = MarketResilienceIndicator::new(
+            Some(500), // 500ms
+            Some(50),  // spread_window_size
+            Some(3),   // levels_to_consume
+            Some(1.0), // spread_increase_threshold
+            Some(0.7), // strong_resilience_threshold
+            Some(0.3), // weak_resilience_threshold
+            Some(0.5), // time_weight
+            Some(0.0), // depth_weight
+            Some(0.5), // spread_weight
+            Some(0.5), // same_side_bias
+            Some(-0.5), // opposite_side_bias
+            None, // impact_notional
+            None, // ewma_alpha
+            None, // ewma_k
+            None, // trade_size_fraction
+            None, // trade_spread_tolerance
+            None, // trade_weight
+            None, // quantile_threshold
+        );
+
+        for spread in [0.1, 0.2, 0.3] {
+            indicator.recent_spreads.push_back(spread);
+        }
+
+        let avg = indicator.fixed_average_spread().to_num::<f64>();
+        assert!((avg - 0.2).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn test_ewma_z_score_flags_spread_shock() {
+        let mut indicator = MarketResilienceIndicator::new(
+            Some(500), // 500ms
+            Some(50),  // spread_window_size
+            Some(3),   // levels_to_consume
+            Some(1.0), // spread_increase_threshold
+            Some(0.7), // strong_resilience_threshold
+            Some(0.3), // weak_resilience_threshold
+            Some(0.5), // time_weight
+            Some(0.0), // depth_weight
+            Some(0.5), // spread_weight
+            Some(0.5), // same_side_bias
+            Some(-0.5), // opposite_side_bias
+            None,      // impact_notional
+            Some(0.3), // ewma_alpha
+            Some(2.0), // ewma_k
+            None, // trade_size_fraction
+            None, // trade_spread_tolerance
+            None, // trade_weight
+            None, // quantile_threshold
+        );
+
+        for spread in [0.10, 0.11, 0.09, 0.10, 0.11, 0.09] {
+            indicator.update_ewma(spread, 0.3);
+        }
+        let baseline_z = indicator.ewma_z_score(0.10).unwrap();
+        assert!(baseline_z.abs() < 2.0);
+
+        indicator.update_ewma(5.0, 0.3);
+        let shocked = indicator.ewma_z_score(5.0).unwrap();
+        assert!(shocked > baseline_z);
+    }
+
+    #[rstest]
+    fn test_quantile_threshold_flags_spread_shock_once_warmed_up() {
+        let mut indicator = MarketResilienceIndicator::new(
+            Some(500), // 500ms
+            Some(50),  // spread_window_size
+            Some(3),   // levels_to_consume
+            Some(1.0), // spread_increase_threshold
+            Some(0.7), // strong_resilience_threshold
+            Some(0.3), // weak_resilience_threshold
+            Some(0.5), // time_weight
+            Some(0.0), // depth_weight
+            Some(0.5), // spread_weight
+            Some(0.5), // same_side_bias
+            Some(-0.5), // opposite_side_bias
+            None,      // impact_notional
+            None,      // ewma_alpha
+            None,      // ewma_k
+            None, // trade_size_fraction
+            None, // trade_spread_tolerance
+            None, // trade_weight
+            Some(0.9), // quantile_threshold
+        );
+
+        assert!(!indicator.is_spread_increased(Some(0.30)));
+
+        for spread in [0.10, 0.11, 0.09, 0.10, 0.12, 0.10, 0.11, 0.09, 0.10, 0.11] {
+            indicator.quantile_estimator.as_mut().unwrap().update(spread);
+        }
+
+        assert!(indicator.quantile_estimator.as_ref().unwrap().quantile().is_some());
+        assert!(!indicator.is_spread_increased(Some(0.11)));
+        assert!(indicator.is_spread_increased(Some(5.0)));
+    }
+
+    #[rstest]
+    fn test_default_score_adapter_matches_linear_weighted_sum() {
+        let indicator = MarketResilienceIndicator::new(
+            Some(500), // 500ms
+            Some(50),  // spread_window_size
+            Some(3),   // levels_to_consume
+            Some(1.0), // spread_increase_threshold
+            Some(0.7), // strong_resilience_threshold
+            Some(0.3), // weak_resilience_threshold
+            Some(0.5), // time_weight
+            Some(0.2), // depth_weight
+            Some(0.3), // spread_weight
+            Some(0.5), // same_side_bias
+            Some(-0.5), // opposite_side_bias
+            None, // impact_notional
+            None, // ewma_alpha
+            None, // ewma_k
+            None, // trade_size_fraction
+            None, // trade_spread_tolerance
+            None, // trade_weight
+            None, // quantile_threshold
+        );
+
+        let score = indicator.score_adapter.compute(1.0, 1.0, 1.0, true);
+        assert!((score - 1.5).abs() < 1e-9);
+
+        let score = indicator.score_adapter.compute(1.0, 1.0, 1.0, false);
+        assert!((score - 0.5).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn test_with_score_adapter_overrides_default() {
+        let indicator = MarketResilienceIndicator::new(
+            Some(500), None, None, None, None, None, None, None, None, None, None, None, None, None,
+            None, // trade_size_fraction
+            None, // trade_spread_tolerance
+            None, // trade_weight
+            None, // quantile_threshold
+        )
+        .with_score_adapter(Box::new(LogisticScoreAdapter {
+            time_weight: 0.5,
+            depth_weight: 0.0,
+            spread_weight: 0.5,
+            steepness: 10.0,
+            same_side_bias: 0.1,
+            opposite_side_bias: -0.1,
+        }));
+
+        let fast_same_side = indicator.score_adapter.compute(1.0, 1.0, 1.0, true);
+        let slow_same_side = indicator.score_adapter.compute(0.2, 0.2, 0.2, true);
+        assert!(fast_same_side > slow_same_side);
+        assert!(fast_same_side <= 1.1);
+    }
+
+    #[rstest]
+    fn test_to_state_from_state_round_trip() {
+        let mut indicator = MarketResilienceIndicator::new(
+            Some(500), None, None, None, None, None, None, None, None, None, None, None, None, None,
+            None, // trade_size_fraction
+            None, // trade_spread_tolerance
+            None, // trade_weight
+            None, // quantile_threshold
+        );
+
+        for spread in [0.1, 0.2, 0.3] {
+            indicator.recent_spreads.push_back(spread);
+        }
+        indicator.score = 0.42;
+        indicator.count = 7;
+        indicator.has_inputs = true;
+        indicator.spread_window_size = 123;
+
+        let state = indicator.to_state();
+
+        // A fresh default-configured indicator resuming from state should not need to be
+        // reconstructed with matching arguments first.
+        let mut restored = MarketResilienceIndicator::new(
+            None, None, None, None, None, None, None, None, None, None, None, None, None, None,
+            None, // trade_size_fraction
+            None, // trade_spread_tolerance
+            None, // trade_weight
+            None, // quantile_threshold
+        );
+        restored.from_state(state);
+
+        assert_eq!(restored.score, 0.42);
+        assert_eq!(restored.count, 7);
+        assert!(restored.has_inputs);
+        assert_eq!(
+            restored.recent_spreads.iter().copied().collect::<Vec<_>>(),
+            vec![0.1, 0.2, 0.3],
+        );
+        assert!(restored.previous_book.is_none());
+        assert_eq!(restored.spread_window_size, 123);
+        assert_eq!(restored.timeout, UnixNanos::from(500 * 1_000_000));
+    }
+
+    #[rstest]
+    fn test_from_state_restores_weights_into_score_adapter() {
+        let saved = MarketResilienceIndicator::new(
+            Some(500),
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(0.1), // time_weight
+            Some(0.2), // depth_weight
+            Some(0.3), // spread_weight
+            Some(0.4), // same_side_bias
+            Some(-0.4), // opposite_side_bias
+            None,
+            None,
+            None,
+            None, // trade_size_fraction
+            None, // trade_spread_tolerance
+            None, // trade_weight
+            None, // quantile_threshold
+        );
+        let state = saved.to_state();
+
+        // Restoring into a default-configured instance must score with the saved weights, not
+        // the loader's construction-time defaults.
+        let mut restored = MarketResilienceIndicator::new(
+            Some(500), None, None, None, None, None, None, None, None, None, None, None, None, None,
+            None, // trade_size_fraction
+            None, // trade_spread_tolerance
+            None, // trade_weight
+            None, // quantile_threshold
+        );
+        restored.from_state(state);
+
+        let saved_score = saved.score_adapter.compute(1.0, 1.0, 1.0, true);
+        let restored_score = restored.score_adapter.compute(1.0, 1.0, 1.0, true);
+        assert_eq!(restored_score, saved_score);
+    }
+
+    #[rstest]
+    fn test_linear_and_inverse_asset_notional() {
+        let linear = LinearAsset { contract_size: 1.0 };
+        assert_eq!(linear.notional(100.0, 2.0), Some(200.0));
+        assert_eq!(linear.notional(0.0, 2.0), None);
+
+        let inverse = InverseAsset { contract_size: 100.0 };
+        assert_eq!(inverse.notional(100.0, 2.0), Some(2.0));
+        assert_eq!(inverse.notional(-1.0, 2.0), None);
+    }
+
+    #[rstest]
+    fn test_with_asset_type_uses_inverse_notional_for_depth() {
+        let indicator = MarketResilienceIndicator::new(
+            Some(500), None, None, None, None, None, None, None, None, None, None, None, None, None,
+            None, // trade_size_fraction
+            None, // trade_spread_tolerance
+            None, // trade_weight
+            None, // quantile_threshold
+        )
+        .with_asset_type(Box::new(InverseAsset { contract_size: 100.0 }));
+
+        let book = create_test_book();
+        let snapshot = BookSnapshot::from_book(&book, indicator.asset_type.as_ref());
+        assert!(snapshot.depth > 0.0);
+    }
+
+    #[rstest]
+    fn test_with_virtual_amm_pool_adds_synthetic_depth() {
+        let indicator = MarketResilienceIndicator::new(
+            Some(500), Some(50), Some(3), None, None, None, None, None, None, None, None, None,
+            None, None,
+            None, // trade_size_fraction
+            None, // trade_spread_tolerance
+            None, // trade_weight
+            None, // quantile_threshold
+        );
+
+        let book = create_test_book();
+        let without_amm = BookSnapshot::from_book(&book, indicator.asset_type.as_ref());
+
+        let indicator = indicator.with_virtual_amm_pool(VirtualAmmPool {
+            base_reserve: 1_000.0,
+            quote_reserve: 10_000.0,
+            tick_size: 0.1,
+        });
+        let with_amm = BookSnapshot::from_book_and_amm(
+            &book,
+            indicator.asset_type.as_ref(),
+            indicator.virtual_amm.as_ref(),
+            indicator.levels_to_consume,
+        );
+
+        assert!(with_amm.depth > without_amm.depth);
+    }
+
     #[rstest]
     fn test_handle_book_updates() {
         let mut indicator = MarketResilienceIndicator::new(
@@ -616,6 +2068,13 @@ mod tests {
             Some(0.5), // spread_weight
             Some(0.5), // same_side_bias
             Some(-0.5), // opposite_side_bias
+            None, // impact_notional
+            None, // ewma_alpha
+            None, // ewma_k
+            None, // trade_size_fraction
+            None, // trade_spread_tolerance
+            None, // trade_weight
+            None, // quantile_threshold
         );
 
         // Initial book state
@@ -659,6 +2118,13 @@ mod tests {
             Some(0.5), // spread_weight
             Some(0.5), // same_side_bias
             Some(-0.5), // opposite_side_bias
+            None, // impact_notional
+            None, // ewma_alpha
+            None, // ewma_k
+            None, // trade_size_fraction
+            None, // trade_spread_tolerance
+            None, // trade_weight
+            None, // quantile_threshold
         );
         
         let mut book = create_test_book();
@@ -689,6 +2155,13 @@ mod tests {
             Some(0.5), // spread_weight
             Some(0.5), // same_side_bias
             Some(-0.5), // opposite_side_bias
+            None, // impact_notional
+            None, // ewma_alpha
+            None, // ewma_k
+            None, // trade_size_fraction
+            None, // trade_spread_tolerance
+            None, // trade_weight
+            None, // quantile_threshold
         );
 
         let book = OrderBook::new(
@@ -715,6 +2188,13 @@ mod tests {
             Some(0.5), // spread_weight
             Some(0.5), // same_side_bias
             Some(-0.5), // opposite_side_bias
+            None, // impact_notional
+            None, // ewma_alpha
+            None, // ewma_k
+            None, // trade_size_fraction
+            None, // trade_spread_tolerance
+            None, // trade_weight
+            None, // quantile_threshold
         );
 
         let mut book = create_test_book();
@@ -724,4 +2204,278 @@ mod tests {
         assert_eq!(indicator.score, 0.0);
         assert_eq!(indicator.bias_side, OrderSide::NoOrderSide);
     }
-} 
\ No newline at end of file
+
+    #[rstest]
+    fn test_trade_consumption_event_opens_and_recovers_into_score() {
+        let mut indicator = MarketResilienceIndicator::new(
+            Some(500), // 500ms
+            Some(50),  // spread_window_size
+            Some(3),   // levels_to_consume
+            Some(1.0), // spread_increase_threshold
+            Some(0.7), // strong_resilience_threshold
+            Some(0.3), // weak_resilience_threshold
+            Some(0.5), // time_weight
+            Some(0.0), // depth_weight
+            Some(0.5), // spread_weight
+            Some(0.5), // same_side_bias
+            Some(-0.5), // opposite_side_bias
+            None, // impact_notional
+            None, // ewma_alpha
+            None, // ewma_k
+            Some(0.5), // trade_size_fraction
+            Some(0.1), // trade_spread_tolerance
+            Some(1.0), // trade_weight
+            None, // quantile_threshold
+        );
+
+        let mut book = create_test_book();
+        add_test_orders(&mut book, 10.0, 10.1, 10.0, 10.0, 0.1, 0.0, 3, 5, 0);
+        indicator.handle_book(&book);
+
+        let mut trade = stub_trade_tick_eth_usdt();
+        trade.aggressor_side = AggressorSide::Buyer;
+        trade.size = Quantity::new(20.0, 0).unwrap(); // exceeds top-of-book ask size * trade_size_fraction
+        trade.ts_event = UnixNanos::from(1_000_000);
+        indicator.handle_trade_tick(&trade);
+
+        assert_eq!(indicator.bias_side, OrderSide::Sell);
+        assert!(indicator.open_trade_event.is_some());
+
+        let mut recovered_book = create_test_book();
+        add_test_orders(&mut recovered_book, 10.0, 10.1, 10.0, 10.0, 0.1, 0.0, 3, 5, 0);
+        recovered_book.ts_last = UnixNanos::from(1_000_000 + 100_000_000); // 100ms later
+        indicator.handle_book(&recovered_book);
+
+        assert!(indicator.open_trade_event.is_none());
+        assert_eq!(indicator.trade_recovery_times.len(), 1);
+        assert!(indicator.trade_recovery_score > 0.0);
+        assert!(indicator.score > 0.0);
+        // `score` is the single well-defined combination of the book-depletion component and the
+        // trade-recovery component, not whichever path last happened to mutate it.
+        let expected = (indicator.resilience_score
+            + indicator.trade_weight * indicator.trade_recovery_score)
+            .clamp(0.0, 1.0);
+        assert_eq!(indicator.score, expected);
+    }
+
+    #[rstest]
+    fn test_trade_consumption_event_times_out_as_worst_case() {
+        let mut indicator = MarketResilienceIndicator::new(
+            Some(500), // 500ms
+            Some(50),  // spread_window_size
+            Some(3),   // levels_to_consume
+            Some(1.0), // spread_increase_threshold
+            Some(0.7), // strong_resilience_threshold
+            Some(0.3), // weak_resilience_threshold
+            Some(0.5), // time_weight
+            Some(0.0), // depth_weight
+            Some(0.5), // spread_weight
+            Some(0.5), // same_side_bias
+            Some(-0.5), // opposite_side_bias
+            None, // impact_notional
+            None, // ewma_alpha
+            None, // ewma_k
+            Some(0.5), // trade_size_fraction
+            Some(0.1), // trade_spread_tolerance
+            None, // trade_weight
+            None, // quantile_threshold
+        );
+
+        let mut book = create_test_book();
+        add_test_orders(&mut book, 10.0, 10.1, 10.0, 10.0, 0.1, 0.0, 3, 5, 0);
+        indicator.handle_book(&book);
+
+        let mut trade = stub_trade_tick_eth_usdt();
+        trade.aggressor_side = AggressorSide::Seller;
+        trade.size = Quantity::new(20.0, 0).unwrap();
+        trade.ts_event = UnixNanos::from(0);
+        indicator.handle_trade_tick(&trade);
+
+        assert_eq!(indicator.bias_side, OrderSide::Buy);
+
+        // Widen the spread so recovery is never observed, and advance past the timeout.
+        let mut later_book = create_test_book();
+        add_test_orders(&mut later_book, 10.0, 11.0, 10.0, 10.0, 0.1, 0.0, 3, 5, 0);
+        later_book.ts_last = UnixNanos::from(600_000_000); // 600ms > 500ms timeout
+        indicator.handle_book(&later_book);
+
+        assert!(indicator.open_trade_event.is_none());
+        assert_eq!(indicator.trade_recovery_times.len(), 1);
+        assert_eq!(indicator.trade_recovery_times[0], 500.0);
+    }
+
+    #[rstest]
+    fn test_trade_tick_ignored_without_top_of_book() {
+        let mut indicator = MarketResilienceIndicator::new(
+            Some(500), // 500ms
+            Some(50),  // spread_window_size
+            Some(3),   // levels_to_consume
+            Some(1.0), // spread_increase_threshold
+            Some(0.7), // strong_resilience_threshold
+            Some(0.3), // weak_resilience_threshold
+            Some(0.5), // time_weight
+            Some(0.0), // depth_weight
+            Some(0.5), // spread_weight
+            Some(0.5), // same_side_bias
+            Some(-0.5), // opposite_side_bias
+            None, // impact_notional
+            None, // ewma_alpha
+            None, // ewma_k
+            Some(0.5), // trade_size_fraction
+            Some(0.1), // trade_spread_tolerance
+            None, // trade_weight
+            None, // quantile_threshold
+        );
+
+        let mut trade = stub_trade_tick_eth_usdt();
+        trade.aggressor_side = AggressorSide::Buyer;
+        trade.size = Quantity::new(20.0, 0).unwrap();
+
+        // No book has been processed yet, so there is no top-of-book to compare against.
+        indicator.handle_trade_tick(&trade);
+
+        assert!(indicator.open_trade_event.is_none());
+        assert_eq!(indicator.bias_side, OrderSide::NoOrderSide);
+    }
+
+    #[rstest]
+    fn test_quote_tick_mode_completes_depletion_recovery_cycle() {
+        let mut indicator = MarketResilienceIndicator::new(
+            Some(500), // 500ms
+            Some(50),  // spread_window_size
+            Some(3),   // levels_to_consume
+            Some(1.0), // spread_increase_threshold
+            Some(0.7), // strong_resilience_threshold
+            Some(0.3), // weak_resilience_threshold
+            Some(0.5), // time_weight
+            Some(0.0), // depth_weight
+            Some(0.5), // spread_weight
+            Some(0.5), // same_side_bias
+            Some(-0.5), // opposite_side_bias
+            None, // impact_notional
+            None, // ewma_alpha
+            None, // ewma_k
+            None, // trade_size_fraction
+            None, // trade_spread_tolerance
+            None, // trade_weight
+            None, // quantile_threshold
+        );
+
+        let mut quote = stub_quote_tick_eth_usdt();
+        quote.bid_price = Price::new(100.0, 2).unwrap();
+        quote.ask_price = Price::new(100.1, 2).unwrap();
+        quote.bid_size = Quantity::new(10.0, 0).unwrap();
+        quote.ask_size = Quantity::new(10.0, 0).unwrap();
+
+        for i in 0..5u64 {
+            quote.ts_event = UnixNanos::from(i);
+            indicator.handle_quote_tick(&quote);
+        }
+
+        // Widen the ask sharply: the spread jumps well beyond the rolling average, opening a
+        // depletion monitoring window on the ask (sell) side.
+        let mut shocked = quote.clone();
+        shocked.ask_price = Price::new(101.0, 2).unwrap();
+        shocked.ts_event = UnixNanos::from(5);
+        indicator.handle_quote_tick(&shocked);
+
+        // The ask snaps back to its original level: recovery is detected and the cycle closes.
+        let mut recovered = quote.clone();
+        recovered.ts_event = UnixNanos::from(6);
+        indicator.handle_quote_tick(&recovered);
+
+        assert!(indicator.is_spread_recovered);
+        assert_eq!(indicator.depletion_side, OrderSide::Sell);
+        assert_eq!(indicator.recovery_side, OrderSide::Sell);
+        assert!(indicator.score > 0.0);
+    }
+
+    #[rstest]
+    fn test_quote_tick_mode_builds_spread_history_without_book() {
+        let mut indicator = MarketResilienceIndicator::new(
+            Some(500), // 500ms
+            Some(50),  // spread_window_size
+            Some(3),   // levels_to_consume
+            Some(1.0), // spread_increase_threshold
+            Some(0.7), // strong_resilience_threshold
+            Some(0.3), // weak_resilience_threshold
+            Some(0.5), // time_weight
+            Some(0.0), // depth_weight
+            Some(0.5), // spread_weight
+            Some(0.5), // same_side_bias
+            Some(-0.5), // opposite_side_bias
+            None, // impact_notional
+            None, // ewma_alpha
+            None, // ewma_k
+            None, // trade_size_fraction
+            None, // trade_spread_tolerance
+            None, // trade_weight
+            None, // quantile_threshold
+        );
+
+        let quote = stub_quote_tick_eth_usdt();
+        indicator.handle_quote_tick(&quote);
+
+        assert!(indicator.has_inputs());
+        assert_eq!(indicator.count, 1);
+        assert_eq!(indicator.recent_spreads.len(), 1);
+    }
+
+    #[rstest]
+    fn test_partial_snapshots_blend_depth_recovery_toward_neutral() {
+        let indicator = MarketResilienceIndicator::new(
+            Some(500), // 500ms
+            Some(50),  // spread_window_size
+            Some(3),   // levels_to_consume
+            Some(1.0), // spread_increase_threshold
+            Some(0.7), // strong_resilience_threshold
+            Some(0.3), // weak_resilience_threshold
+            Some(0.5), // time_weight
+            Some(0.5), // depth_weight
+            Some(0.0), // spread_weight
+            Some(0.5), // same_side_bias
+            Some(-0.5), // opposite_side_bias
+            None, // impact_notional
+            None, // ewma_alpha
+            None, // ewma_k
+            None, // trade_size_fraction
+            None, // trade_spread_tolerance
+            None, // trade_weight
+            None, // quantile_threshold
+        );
+
+        let full_initial = BookSnapshot {
+            spread: None,
+            depth: 100.0,
+            is_partial: false,
+            ts_last: UnixNanos::from(0),
+        };
+        let full_end = BookSnapshot {
+            spread: None,
+            depth: 10.0,
+            is_partial: false,
+            ts_last: UnixNanos::from(1),
+        };
+        let (_, _, full_depth_recovery) =
+            indicator.calculate_normalized_metrics(&full_initial, &full_end, UnixNanos::from(1));
+
+        let partial_initial = BookSnapshot {
+            is_partial: true,
+            ..full_initial
+        };
+        let partial_end = BookSnapshot {
+            is_partial: true,
+            ..full_end
+        };
+        let (_, _, partial_depth_recovery) = indicator.calculate_normalized_metrics(
+            &partial_initial,
+            &partial_end,
+            UnixNanos::from(1),
+        );
+
+        // Same raw depth ratio, but the partial (quote-tick) pair is pulled toward the neutral
+        // midpoint rather than passing the full ratio straight through.
+        assert!(partial_depth_recovery > full_depth_recovery);
+        assert!((partial_depth_recovery.to_num::<f64>() - 0.3).abs() < 1e-9);
+    }
+}
\ No newline at end of file
@@ -13,25 +13,80 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+use std::collections::BTreeMap;
 use std::fmt::Display;
 
+use fixed::types::I80F48;
+use nautilus_core::nanos::UnixNanos;
 use nautilus_model::{
-    orderbook::book::OrderBook, 
-    data::trade::TradeTick, 
+    orderbook::{book::OrderBook, delta::OrderBookDelta},
+    data::trade::TradeTick,
     types::quantity::Quantity,
-    enums::AggressorSide,
+    enums::{AggressorSide, BookAction, OrderSide},
 };
 
+use crate::book::virtual_amm::VirtualAmmPool;
 use crate::indicator::Indicator;
 
+/// Converts an `f64` into the fixed-point type used for deterministic volume accumulation.
+fn fx(value: f64) -> I80F48 {
+    I80F48::from_num(value)
+}
+
+/// Converts a `Quantity` to the fixed-point type via its exact raw integer and precision, rather
+/// than its `f64` approximation, so repeated accumulation does not drift.
+fn quantity_to_fixed(quantity: &Quantity) -> I80F48 {
+    I80F48::from_num(quantity.raw) / I80F48::from_num(10u64.pow(u32::from(quantity.precision)))
+}
+
+/// Lower bound for a decay exponent below which the weight is treated as fully decayed, and upper
+/// bound above which it is treated as undecayed. Keeps [`protected_exp`] free of `NaN`/`inf`
+/// results for very large gaps between updates or a very small `half_life_ns`, in the spirit of
+/// the protected exponential used by Zeitgeist's combinatorial betting math.
+const PROTECTED_EXP_LOWER_BOUND: f64 = -80.0;
+const PROTECTED_EXP_UPPER_BOUND: f64 = 0.0;
+
+/// Computes `exp(x)` with the input clamped so that very negative exponents return `0.0` (full
+/// decay) instead of underflowing, and non-negative exponents return `1.0` instead of overflowing.
+fn protected_exp(x: f64) -> f64 {
+    if x < PROTECTED_EXP_LOWER_BOUND {
+        0.0
+    } else if x > PROTECTED_EXP_UPPER_BOUND {
+        1.0
+    } else {
+        x.exp()
+    }
+}
+
+/// Computes the exponential-decay weight `exp(-ln2 * dt_ns / half_life_ns)` for a gap of
+/// `dt_ns` nanoseconds, protected against a zero or vanishingly small `half_life_ns`.
+fn decay_alpha(dt_ns: u64, half_life_ns: u64) -> f64 {
+    if half_life_ns == 0 {
+        return 0.0;
+    }
+    let exponent = -std::f64::consts::LN_2 * (dt_ns as f64) / (half_life_ns as f64);
+    protected_exp(exponent)
+}
+
 /// An indicator which calculates the ratio of trade volume to order volume in the order book.
-/// 
+///
 /// The T2O ratio measures the proportion of executed trades to placed orders in the market.
-/// It's calculated by dividing the volume of executed trades by the total volume of orders 
+/// It's calculated by dividing the volume of executed trades by the total volume of orders
 /// (including unexecuted ones) in a given time frame.
-/// 
-/// A high T2O ratio may indicate strong demand or supply at certain price levels, while a 
+///
+/// A high T2O ratio may indicate strong demand or supply at certain price levels, while a
 /// low ratio may suggest indecision or lack of conviction in the market.
+///
+/// Running volume sums are accumulated in `I80F48` fixed-point with saturating arithmetic, rather
+/// than `f64`, so the indicator's output is bit-reproducible across backtests and live runs
+/// regardless of the order trades arrive in; only the public [`Self::value`] is converted to
+/// `f64`.
+///
+/// By default the ratio resets over a hard window via [`Self::reset_calculation`], which makes it
+/// jump discontinuously at window boundaries. Calling [`Self::with_half_life`] switches to a
+/// continuously time-decayed ratio instead: `ewma_trade` and `ewma_order` decay by
+/// `exp(-ln2 * dt / half_life_ns)` on every update, where `dt` is the time since the previous
+/// update, protected against large gaps or a tiny half-life via [`protected_exp`].
 #[repr(C)]
 #[derive(Debug)]
 #[cfg_attr(
@@ -44,9 +99,18 @@ pub struct TradeToOrderRatio {
     pub initialized: bool,
     has_inputs: bool,
     depth: usize,
-    trade_volume: f64,
-    order_volume: f64,
-    initial_order_volume: f64,
+    trade_volume: I80F48,
+    order_volume: I80F48,
+    initial_order_volume: I80F48,
+    bid_levels: BTreeMap<i64, I80F48>,
+    ask_levels: BTreeMap<i64, I80F48>,
+    bid_volume: I80F48,
+    ask_volume: I80F48,
+    values: Vec<f64>,
+    half_life_ns: Option<u64>,
+    ewma_trade: I80F48,
+    ewma_order: I80F48,
+    last_update_ns: Option<UnixNanos>,
 }
 
 impl Display for TradeToOrderRatio {
@@ -70,14 +134,14 @@ impl Indicator for TradeToOrderRatio {
 
     fn handle_book(&mut self, book: &OrderBook) {
         // Calculate total volume from order book up to specified depth
-        let mut total_volume = 0.0;
-        
+        let mut total_volume = I80F48::ZERO;
+
         // Process bids
         for (i, level) in book.bids().iter().enumerate() {
             if i >= self.depth {
                 break;
             }
-            total_volume += level.size();
+            total_volume = total_volume.saturating_add(fx(level.size()));
         }
 
         // Process asks
@@ -85,22 +149,68 @@ impl Indicator for TradeToOrderRatio {
             if i >= self.depth {
                 break;
             }
-            total_volume += level.size();
+            total_volume = total_volume.saturating_add(fx(level.size()));
         }
 
         self.order_volume = total_volume;
-        if self.initial_order_volume == 0.0 {
+        if self.initial_order_volume == I80F48::ZERO {
             self.initial_order_volume = total_volume;
         }
+        self.apply_decay(book.ts_last, I80F48::ZERO, total_volume);
         self.update();
     }
 
-    fn handle_trade_tick(&mut self, trade: &TradeTick) {
-        let volume = trade.size.as_f64();
-        match trade.aggressor_side {
-            AggressorSide::Buyer => self.trade_volume += volume,
-            AggressorSide::Seller => self.trade_volume -= volume,
+    fn handle_book_delta(&mut self, delta: &OrderBookDelta) {
+        if delta.action == BookAction::Clear {
+            self.bid_levels.clear();
+            self.ask_levels.clear();
+            self.bid_volume = I80F48::ZERO;
+            self.ask_volume = I80F48::ZERO;
+        } else {
+            let price_raw = delta.order.price.raw;
+            let levels = match delta.order.side {
+                OrderSide::Buy => &mut self.bid_levels,
+                OrderSide::Sell => &mut self.ask_levels,
+                OrderSide::NoOrderSide => return,
+            };
+
+            match delta.action {
+                BookAction::Add | BookAction::Update => {
+                    levels.insert(price_raw, quantity_to_fixed(&delta.order.size));
+                }
+                BookAction::Delete => {
+                    levels.remove(&price_raw);
+                }
+                BookAction::Clear => unreachable!(),
+            }
+
+            match delta.order.side {
+                OrderSide::Buy => {
+                    self.bid_volume = Self::top_depth_volume(&self.bid_levels, self.depth, true);
+                }
+                OrderSide::Sell => {
+                    self.ask_volume = Self::top_depth_volume(&self.ask_levels, self.depth, false);
+                }
+                OrderSide::NoOrderSide => {}
+            }
+        }
+
+        self.order_volume = self.bid_volume.saturating_add(self.ask_volume);
+        if self.initial_order_volume == I80F48::ZERO {
+            self.initial_order_volume = self.order_volume;
         }
+        self.apply_decay(delta.ts_event, I80F48::ZERO, self.order_volume);
+        self.update();
+    }
+
+    fn handle_trade_tick(&mut self, trade: &TradeTick) {
+        let volume = quantity_to_fixed(&trade.size);
+        let signed_volume = match trade.aggressor_side {
+            AggressorSide::Buyer => volume,
+            AggressorSide::Seller => -volume,
+        };
+        self.trade_volume = self.trade_volume.saturating_add(signed_volume);
+        self.apply_decay(trade.ts_event, signed_volume, I80F48::ZERO);
         self.update();
     }
 
@@ -109,9 +219,17 @@ impl Indicator for TradeToOrderRatio {
         self.count = 0;
         self.has_inputs = false;
         self.initialized = false;
-        self.trade_volume = 0.0;
-        self.order_volume = 0.0;
-        self.initial_order_volume = 0.0;
+        self.trade_volume = I80F48::ZERO;
+        self.order_volume = I80F48::ZERO;
+        self.initial_order_volume = I80F48::ZERO;
+        self.bid_levels.clear();
+        self.ask_levels.clear();
+        self.bid_volume = I80F48::ZERO;
+        self.ask_volume = I80F48::ZERO;
+        self.values.clear();
+        self.ewma_trade = I80F48::ZERO;
+        self.ewma_order = I80F48::ZERO;
+        self.last_update_ns = None;
     }
 }
 
@@ -130,29 +248,135 @@ impl TradeToOrderRatio {
             has_inputs: false,
             initialized: false,
             depth,
-            trade_volume: 0.0,
-            order_volume: 0.0,
-            initial_order_volume: 0.0,
+            trade_volume: I80F48::ZERO,
+            order_volume: I80F48::ZERO,
+            initial_order_volume: I80F48::ZERO,
+            bid_levels: BTreeMap::new(),
+            ask_levels: BTreeMap::new(),
+            bid_volume: I80F48::ZERO,
+            ask_volume: I80F48::ZERO,
+            values: Vec::new(),
+            half_life_ns: None,
+            ewma_trade: I80F48::ZERO,
+            ewma_order: I80F48::ZERO,
+            last_update_ns: None,
         }
     }
 
+    /// Switches the ratio to continuously time-decayed accumulation with the given half-life in
+    /// nanoseconds, instead of the default hard window reset via [`Self::reset_calculation`].
+    #[must_use]
+    pub fn with_half_life(mut self, half_life_ns: u64) -> Self {
+        self.half_life_ns = Some(half_life_ns);
+        self
+    }
+
+    /// Returns the full series of computed ratio values recorded since construction or the last
+    /// [`Self::reset`].
+    #[must_use]
+    pub fn values(&self) -> &[f64] {
+        &self.values
+    }
+
+    /// Updates the ratio from a discrete `OrderBook` plus a [`VirtualAmmPool`] representing a
+    /// secondary liquidity source on a hybrid venue, so `order_volume` reflects total executable
+    /// depth rather than only the CLOB portion.
+    ///
+    /// The book's own levels are consumed up to `self.depth` first (same as [`Self::handle_book`]);
+    /// any remaining depth budget is then filled from the AMM's synthetic levels on each side.
+    pub fn handle_book_with_amm(&mut self, book: &OrderBook, amm: &VirtualAmmPool) {
+        let mut total_volume = I80F48::ZERO;
+
+        let bid_count = book.bids().iter().take(self.depth).count();
+        for level in book.bids().iter().take(self.depth) {
+            total_volume = total_volume.saturating_add(fx(level.size()));
+        }
+        for (_, size) in amm.synthetic_levels(OrderSide::Buy, self.depth.saturating_sub(bid_count)) {
+            total_volume = total_volume.saturating_add(fx(size));
+        }
+
+        let ask_count = book.asks().iter().take(self.depth).count();
+        for level in book.asks().iter().take(self.depth) {
+            total_volume = total_volume.saturating_add(fx(level.size()));
+        }
+        for (_, size) in amm.synthetic_levels(OrderSide::Sell, self.depth.saturating_sub(ask_count)) {
+            total_volume = total_volume.saturating_add(fx(size));
+        }
+
+        self.order_volume = total_volume;
+        if self.initial_order_volume == I80F48::ZERO {
+            self.initial_order_volume = total_volume;
+        }
+        self.apply_decay(book.ts_last, I80F48::ZERO, total_volume);
+        self.update();
+    }
+
     /// Resets calculation for a new time window while maintaining the depth setting.
     pub fn reset_calculation(&mut self) {
-        self.trade_volume = 0.0;
+        self.trade_volume = I80F48::ZERO;
         self.initial_order_volume = self.order_volume;
         self.value = 0.0;
         self.count = 0;
     }
 
+    /// Sums the top `depth` level sizes from a depth-limited level map, reading from the best
+    /// price outward (descending for bids, ascending for asks), with saturating addition rather
+    /// than wrapping on overflow.
+    fn top_depth_volume(levels: &BTreeMap<i64, I80F48>, depth: usize, reverse: bool) -> I80F48 {
+        if reverse {
+            levels
+                .values()
+                .rev()
+                .take(depth)
+                .fold(I80F48::ZERO, |acc, &v| acc.saturating_add(v))
+        } else {
+            levels
+                .values()
+                .take(depth)
+                .fold(I80F48::ZERO, |acc, &v| acc.saturating_add(v))
+        }
+    }
+
+    /// Decays `ewma_trade`/`ewma_order` towards zero for the time elapsed since the last update,
+    /// then folds in this event's contribution. A no-op when [`Self::with_half_life`] has not
+    /// been called, so hard-window mode never touches the decayed accumulators.
+    fn apply_decay(&mut self, ts: UnixNanos, trade_increment: I80F48, order_snapshot: I80F48) {
+        let Some(half_life_ns) = self.half_life_ns else {
+            return;
+        };
+
+        let alpha = match self.last_update_ns {
+            Some(previous) => decay_alpha(ts.as_u64().saturating_sub(previous.as_u64()), half_life_ns),
+            None => 0.0, // no prior observation to decay from yet
+        };
+        let alpha = fx(alpha);
+
+        self.ewma_trade = self.ewma_trade.saturating_mul(alpha).saturating_add(trade_increment);
+        self.ewma_order = self.ewma_order.saturating_mul(alpha).saturating_add(order_snapshot);
+        self.last_update_ns = Some(ts);
+    }
+
     fn update(&mut self) {
         self.has_inputs = true;
         self.count += 1;
 
-        let order_volume_delta = self.order_volume - self.initial_order_volume;
-        if order_volume_delta != 0.0 {
-            self.value = self.trade_volume / order_volume_delta;
-            self.initialized = true;
+        if self.half_life_ns.is_some() {
+            if self.ewma_order != I80F48::ZERO {
+                if let Some(ratio) = self.ewma_trade.checked_div(self.ewma_order) {
+                    self.value = ratio.to_num::<f64>();
+                    self.initialized = true;
+                }
+            }
+        } else {
+            let order_volume_delta = self.order_volume.saturating_sub(self.initial_order_volume);
+            if order_volume_delta != I80F48::ZERO {
+                if let Some(ratio) = self.trade_volume.checked_div(order_volume_delta) {
+                    self.value = ratio.to_num::<f64>();
+                    self.initialized = true;
+                }
+            }
         }
+        self.values.push(self.value);
     }
 }
 
@@ -161,9 +385,12 @@ impl TradeToOrderRatio {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
+    use nautilus_core::nanos::UnixNanos;
     use nautilus_model::{
         identifiers::InstrumentId,
+        orderbook::{book::BookOrder, delta::OrderBookDelta},
         stubs::{stub_order_book_mbp, stub_trade_tick_eth_usdt},
+        types::price::Price,
     };
     use rstest::rstest;
 
@@ -198,8 +425,8 @@ mod tests {
         ratio.handle_book(&book);
 
         assert_eq!(ratio.count, 1);
-        assert_eq!(ratio.order_volume, 200.0);
-        assert_eq!(ratio.initial_order_volume, 200.0);
+        assert_eq!(ratio.order_volume, fx(200.0));
+        assert_eq!(ratio.initial_order_volume, fx(200.0));
         assert_eq!(ratio.value, 0.0);
         assert!(ratio.has_inputs);
     }
@@ -226,8 +453,8 @@ mod tests {
         ratio.handle_trade_tick(&trade);
 
         assert_eq!(ratio.count, 2);
-        assert_eq!(ratio.trade_volume, 1.0);
-        assert_eq!(ratio.order_volume, 250.0);
+        assert_eq!(ratio.trade_volume, fx(1.0));
+        assert_eq!(ratio.order_volume, fx(250.0));
         assert_eq!(ratio.value, 0.02); // 1.0 / (250.0 - 250.0)
         assert!(ratio.initialized);
     }
@@ -254,8 +481,8 @@ mod tests {
         ratio.handle_trade_tick(&trade);
 
         assert_eq!(ratio.count, 2);
-        assert_eq!(ratio.trade_volume, -1.0);
-        assert_eq!(ratio.order_volume, 250.0);
+        assert_eq!(ratio.trade_volume, fx(-1.0));
+        assert_eq!(ratio.order_volume, fx(250.0));
         assert_eq!(ratio.value, -0.02); // -1.0 / (250.0 - 250.0)
         assert!(ratio.initialized);
     }
@@ -283,8 +510,8 @@ mod tests {
 
         assert_eq!(ratio.count, 0);
         assert_eq!(ratio.value, 0.0);
-        assert_eq!(ratio.trade_volume, 0.0);
-        assert_eq!(ratio.initial_order_volume, 200.0);
+        assert_eq!(ratio.trade_volume, I80F48::ZERO);
+        assert_eq!(ratio.initial_order_volume, fx(200.0));
         assert!(ratio.initialized);
     }
 
@@ -311,10 +538,153 @@ mod tests {
 
         assert_eq!(ratio.count, 0);
         assert_eq!(ratio.value, 0.0);
-        assert_eq!(ratio.trade_volume, 0.0);
-        assert_eq!(ratio.order_volume, 0.0);
-        assert_eq!(ratio.initial_order_volume, 0.0);
+        assert_eq!(ratio.trade_volume, I80F48::ZERO);
+        assert_eq!(ratio.order_volume, I80F48::ZERO);
+        assert_eq!(ratio.initial_order_volume, I80F48::ZERO);
         assert!(!ratio.initialized);
         assert!(!ratio.has_inputs);
     }
-} 
\ No newline at end of file
+
+    #[rstest]
+    fn test_handle_book_delta_add_and_delete() {
+        let mut ratio = TradeToOrderRatio::new(2);
+
+        let bid_order = BookOrder::new(OrderSide::Buy, Price::from("100.00"), Quantity::from("10"), 1);
+        let add_delta = OrderBookDelta::new(
+            InstrumentId::from("ETH/USDT.BINANCE"),
+            BookAction::Add,
+            bid_order,
+            0,
+            0,
+            UnixNanos::default(),
+            UnixNanos::default(),
+        );
+        ratio.handle_book_delta(&add_delta);
+
+        assert_eq!(ratio.order_volume, fx(10.0));
+        assert!(ratio.has_inputs);
+
+        let delete_delta = OrderBookDelta::new(
+            InstrumentId::from("ETH/USDT.BINANCE"),
+            BookAction::Delete,
+            bid_order,
+            0,
+            1,
+            UnixNanos::default(),
+            UnixNanos::default(),
+        );
+        ratio.handle_book_delta(&delete_delta);
+
+        assert_eq!(ratio.order_volume, I80F48::ZERO);
+    }
+
+    #[rstest]
+    fn test_values_records_one_entry_per_update() {
+        let mut ratio = TradeToOrderRatio::new(2);
+        let book = stub_order_book_mbp(
+            InstrumentId::from("ETH/USDT.BINANCE"),
+            101.0,
+            100.0,
+            100.0,
+            100.0,
+            2,
+            0.01,
+            0,
+            100.0,
+            10,
+        );
+        let trade = stub_trade_tick_eth_usdt();
+
+        ratio.handle_book(&book);
+        ratio.handle_trade_tick(&trade);
+
+        assert_eq!(ratio.values().len(), 2);
+        assert_eq!(ratio.values()[1], ratio.value);
+    }
+
+    #[rstest]
+    fn test_quantity_to_fixed_matches_raw_and_precision() {
+        let quantity = Quantity::new(12.345, 3).unwrap();
+        assert_eq!(quantity_to_fixed(&quantity), fx(12.345));
+    }
+
+    #[rstest]
+    fn test_protected_exp_clamps_extreme_exponents() {
+        assert_eq!(protected_exp(-1_000.0), 0.0);
+        assert_eq!(protected_exp(1.0), 1.0);
+        assert!((protected_exp(0.0) - 1.0).abs() < 1e-12);
+    }
+
+    #[rstest]
+    fn test_decay_alpha_zero_half_life_fully_decays() {
+        assert_eq!(decay_alpha(100, 0), 0.0);
+    }
+
+    #[rstest]
+    fn test_half_life_mode_never_hard_resets() {
+        let mut ratio = TradeToOrderRatio::new(2).with_half_life(100_000_000); // 100ms
+        let book = stub_order_book_mbp(
+            InstrumentId::from("ETH/USDT.BINANCE"),
+            101.0,
+            100.0,
+            100.0,
+            100.0,
+            2,
+            0.01,
+            0,
+            100.0,
+            10,
+        );
+        let mut trade = stub_trade_tick_eth_usdt();
+        trade.aggressor_side = AggressorSide::Buyer;
+        trade.ts_event = UnixNanos::from(0);
+
+        ratio.handle_book(&book);
+        ratio.handle_trade_tick(&trade);
+        assert!(ratio.initialized);
+        let value_before_gap = ratio.value;
+
+        // A gap of one half-life halves the weight of the earlier book snapshot, moving the ratio.
+        let mut later_trade = trade;
+        later_trade.ts_event = UnixNanos::from(100_000_000); // 1 half-life later
+        ratio.handle_trade_tick(&later_trade);
+
+        assert_ne!(ratio.value, value_before_gap);
+
+        // reset_calculation() is a hard-window concept; it must not disturb the decayed
+        // accumulators that decay mode relies on instead.
+        let ewma_order_before = ratio.ewma_order;
+        ratio.reset_calculation();
+        assert_eq!(ratio.ewma_order, ewma_order_before);
+    }
+
+    #[rstest]
+    fn test_handle_book_with_amm_adds_synthetic_depth_up_to_remaining_budget() {
+        let mut ratio = TradeToOrderRatio::new(3);
+        let book = stub_order_book_mbp(
+            InstrumentId::from("ETH/USDT.BINANCE"),
+            101.0,
+            100.0,
+            100.0,
+            100.0,
+            2, // only 2 real levels per side, depth budget is 3
+            0.01,
+            0,
+            100.0,
+            10,
+        );
+        let amm = VirtualAmmPool {
+            base_reserve: 1_000.0,
+            quote_reserve: 100_000.0, // spot = 100.0
+            tick_size: 0.5,
+        };
+
+        ratio.handle_book_with_amm(&book, &amm);
+
+        // 2 real levels per side (100.0 each = 400.0 total) plus exactly 1 synthetic level per
+        // side to fill the remaining depth budget of 1.
+        let expected_synthetic = amm.synthetic_levels(OrderSide::Buy, 1)[0].1
+            + amm.synthetic_levels(OrderSide::Sell, 1)[0].1;
+        assert_eq!(ratio.order_volume, fx(400.0 + expected_synthetic));
+    }
+}
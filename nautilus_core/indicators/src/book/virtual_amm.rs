@@ -0,0 +1,153 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_model::enums::OrderSide;
+
+/// A constant-product (`x * y = k`) automated market maker pool, used as a secondary liquidity
+/// source alongside a discrete order book on hybrid venues that route across both a CLOB and an
+/// AMM.
+///
+/// Book indicators that only understand a discrete `OrderBook` can combine its real depth with
+/// [`Self::synthetic_levels`] to approximate the total liquidity executable on such a venue.
+#[derive(Debug, Clone, Copy)]
+pub struct VirtualAmmPool {
+    /// The pool's base-asset reserve (`x`).
+    pub base_reserve: f64,
+    /// The pool's quote-asset reserve (`y`).
+    pub quote_reserve: f64,
+    /// The price increment between successive synthetic levels.
+    pub tick_size: f64,
+}
+
+impl VirtualAmmPool {
+    /// Returns the pool's current spot price, in quote currency per unit of base currency.
+    #[must_use]
+    pub fn spot_price(&self) -> f64 {
+        self.quote_reserve / self.base_reserve
+    }
+
+    /// Slices the constant-product curve into up to `levels` synthetic order-book levels on
+    /// `side`, starting one `tick_size` away from the spot price and stepping outward.
+    ///
+    /// Each returned `(price, size)` pair gives the incremental base quantity obtainable between
+    /// that level and the previous one -- i.e. the synthetic level's `size()` -- so real and
+    /// synthetic depth can be summed directly. Stops early if a price would cross zero, or once
+    /// the reserves are too small to move further (avoiding a `NaN`/negative increment).
+    #[must_use]
+    pub fn synthetic_levels(&self, side: OrderSide, levels: usize) -> Vec<(f64, f64)> {
+        if self.base_reserve <= 0.0 || self.quote_reserve <= 0.0 || self.tick_size <= 0.0 {
+            return Vec::new();
+        }
+
+        let k = self.base_reserve * self.quote_reserve;
+        let spot = self.spot_price();
+        let mut synthetic = Vec::with_capacity(levels);
+        let mut previous_base = self.base_reserve;
+
+        for i in 1..=levels {
+            let price = match side {
+                // Buying base from the pool pushes the price up.
+                OrderSide::Sell => spot + self.tick_size * i as f64,
+                // Selling base into the pool pushes the price down.
+                OrderSide::Buy => spot - self.tick_size * i as f64,
+                OrderSide::NoOrderSide => break,
+            };
+            if price <= 0.0 {
+                break;
+            }
+
+            let base_at_price = (k / price).sqrt();
+            let incremental_size = match side {
+                OrderSide::Sell => previous_base - base_at_price,
+                OrderSide::Buy => base_at_price - previous_base,
+                OrderSide::NoOrderSide => unreachable!(),
+            };
+            if incremental_size <= 0.0 {
+                break;
+            }
+
+            synthetic.push((price, incremental_size));
+            previous_base = base_at_price;
+        }
+
+        synthetic
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_spot_price_is_quote_over_base() {
+        let pool = VirtualAmmPool {
+            base_reserve: 100.0,
+            quote_reserve: 10_000.0,
+            tick_size: 1.0,
+        };
+        assert_eq!(pool.spot_price(), 100.0);
+    }
+
+    #[rstest]
+    fn test_synthetic_levels_prices_step_away_from_spot() {
+        let pool = VirtualAmmPool {
+            base_reserve: 100.0,
+            quote_reserve: 10_000.0,
+            tick_size: 1.0,
+        };
+
+        let asks = pool.synthetic_levels(OrderSide::Sell, 3);
+        assert_eq!(asks.len(), 3);
+        assert_eq!(asks[0].0, 101.0);
+        assert_eq!(asks[1].0, 102.0);
+        assert_eq!(asks[2].0, 103.0);
+        assert!(asks.iter().all(|(_, size)| *size > 0.0));
+
+        let bids = pool.synthetic_levels(OrderSide::Buy, 3);
+        assert_eq!(bids.len(), 3);
+        assert_eq!(bids[0].0, 99.0);
+        assert_eq!(bids[1].0, 98.0);
+        assert_eq!(bids[2].0, 97.0);
+        assert!(bids.iter().all(|(_, size)| *size > 0.0));
+    }
+
+    #[rstest]
+    fn test_synthetic_levels_empty_for_zero_reserves() {
+        let pool = VirtualAmmPool {
+            base_reserve: 0.0,
+            quote_reserve: 10_000.0,
+            tick_size: 1.0,
+        };
+        assert!(pool.synthetic_levels(OrderSide::Sell, 5).is_empty());
+    }
+
+    #[rstest]
+    fn test_synthetic_levels_stops_before_crossing_zero_price() {
+        let pool = VirtualAmmPool {
+            base_reserve: 100.0,
+            quote_reserve: 1_000.0, // spot = 10.0
+            tick_size: 5.0,
+        };
+        // Only one bid level fits strictly above zero (10.0 - 5.0 = 5.0); the next would be <= 0.
+        let bids = pool.synthetic_levels(OrderSide::Buy, 10);
+        assert_eq!(bids.len(), 1);
+        assert_eq!(bids[0].0, 5.0);
+    }
+}
@@ -0,0 +1,52 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_model::{
+    data::{bar::Bar, quote::QuoteTick, trade::TradeTick},
+    orderbook::{book::OrderBook, delta::OrderBookDelta},
+};
+
+/// The common interface implemented by all indicators in this crate.
+///
+/// Implementors update their internal state from one or more of the `handle_*` methods, each of
+/// which defaults to a no-op so an indicator only needs to implement the event types it consumes.
+pub trait Indicator {
+    /// Returns the display name of the indicator.
+    fn name(&self) -> String;
+
+    /// Returns `true` once the indicator has received at least one input.
+    fn has_inputs(&self) -> bool;
+
+    /// Returns `true` once the indicator has received enough inputs to produce a valid value.
+    fn initialized(&self) -> bool;
+
+    /// Updates the indicator with a full order book snapshot.
+    fn handle_book(&mut self, _book: &OrderBook) {}
+
+    /// Updates the indicator with an incremental order book delta.
+    fn handle_book_delta(&mut self, _delta: &OrderBookDelta) {}
+
+    /// Updates the indicator with a bar.
+    fn handle_bar(&mut self, _bar: &Bar) {}
+
+    /// Updates the indicator with a quote tick.
+    fn handle_quote_tick(&mut self, _quote: &QuoteTick) {}
+
+    /// Updates the indicator with a trade tick.
+    fn handle_trade_tick(&mut self, _trade: &TradeTick) {}
+
+    /// Resets the indicator to a fresh state, clearing all inputs and outputs.
+    fn reset(&mut self);
+}
@@ -0,0 +1,266 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::VecDeque;
+use std::fmt::Display;
+
+use nautilus_core::nanos::UnixNanos;
+use nautilus_model::data::bar::Bar;
+
+use crate::indicator::Indicator;
+
+/// A classic structural-momentum indicator that tracks swing highs and lows over a rolling
+/// window of bars.
+///
+/// `direction` is `1` while the current high is the highest of the last `period` bars, `-1`
+/// while the current low is the lowest, and flips (setting `changed` for that bar only) whenever
+/// the opposite extreme takes over. `length` is the price travel of the current swing, measured
+/// from the last opposite turning point to the current extreme.
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")
+)]
+pub struct Swings {
+    pub direction: i8,
+    pub changed: bool,
+    pub high_price: f64,
+    pub low_price: f64,
+    pub high_datetime: UnixNanos,
+    pub low_datetime: UnixNanos,
+    pub since_high: usize,
+    pub since_low: usize,
+    pub length: f64,
+    pub count: usize,
+    pub initialized: bool,
+    has_inputs: bool,
+    period: usize,
+    highs: VecDeque<f64>,
+    lows: VecDeque<f64>,
+    last_high_turning_point: Option<f64>,
+    last_low_turning_point: Option<f64>,
+}
+
+impl Display for Swings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.name(), self.period)
+    }
+}
+
+impl Indicator for Swings {
+    fn name(&self) -> String {
+        stringify!(Swings).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.has_inputs = true;
+        self.count += 1;
+        self.changed = false;
+
+        let high = bar.high.as_f64();
+        let low = bar.low.as_f64();
+
+        if self.highs.len() == self.period {
+            self.highs.pop_front();
+        }
+        self.highs.push_back(high);
+
+        if self.lows.len() == self.period {
+            self.lows.pop_front();
+        }
+        self.lows.push_back(low);
+
+        self.since_high += 1;
+        self.since_low += 1;
+
+        if self.highs.len() < self.period {
+            return;
+        }
+
+        // Seed both extremes from the warmed-up window before the very first turning point is
+        // captured, so the first swing's `length` is measured from the window's actual opposite
+        // extreme instead of the `0.0` initializer.
+        if self.direction == 0 {
+            self.high_price = self.highs.iter().copied().fold(f64::MIN, f64::max);
+            self.low_price = self.lows.iter().copied().fold(f64::MAX, f64::min);
+        }
+
+        let is_new_high = self.highs.iter().copied().fold(f64::MIN, f64::max) == high;
+        let is_new_low = self.lows.iter().copied().fold(f64::MAX, f64::min) == low;
+
+        let previous_direction = self.direction;
+
+        if is_new_high {
+            if previous_direction != 1 {
+                self.changed = true;
+                self.last_low_turning_point = Some(self.low_price);
+                self.since_high = 0;
+            }
+            self.direction = 1;
+            self.high_price = high;
+            self.high_datetime = bar.ts_event;
+        } else if is_new_low {
+            if previous_direction != -1 {
+                self.changed = true;
+                self.last_high_turning_point = Some(self.high_price);
+                self.since_low = 0;
+            }
+            self.direction = -1;
+            self.low_price = low;
+            self.low_datetime = bar.ts_event;
+        }
+
+        self.length = match self.direction {
+            1 => self.high_price - self.last_low_turning_point.unwrap_or(self.low_price),
+            -1 => self.last_high_turning_point.unwrap_or(self.high_price) - self.low_price,
+            _ => 0.0,
+        }
+        .abs();
+
+        self.initialized = true;
+    }
+
+    fn reset(&mut self) {
+        self.direction = 0;
+        self.changed = false;
+        self.high_price = 0.0;
+        self.low_price = 0.0;
+        self.high_datetime = UnixNanos::default();
+        self.low_datetime = UnixNanos::default();
+        self.since_high = 0;
+        self.since_low = 0;
+        self.length = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+        self.highs.clear();
+        self.lows.clear();
+        self.last_high_turning_point = None;
+        self.last_low_turning_point = None;
+    }
+}
+
+impl Swings {
+    /// Creates a new [`Swings`] instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `period` - The number of bars used to detect a swing high or low.
+    #[must_use]
+    pub fn new(period: usize) -> Self {
+        Self {
+            direction: 0,
+            changed: false,
+            high_price: 0.0,
+            low_price: 0.0,
+            high_datetime: UnixNanos::default(),
+            low_datetime: UnixNanos::default(),
+            since_high: 0,
+            since_low: 0,
+            length: 0.0,
+            count: 0,
+            initialized: false,
+            has_inputs: false,
+            period,
+            highs: VecDeque::with_capacity(period),
+            lows: VecDeque::with_capacity(period),
+            last_high_turning_point: None,
+            last_low_turning_point: None,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{stubs::stub_bar_ethusdt_binance, types::price::Price};
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_initialized() {
+        let swings = Swings::new(3);
+        let display_str = format!("{swings}");
+        assert_eq!(display_str, "Swings(3)");
+        assert_eq!(swings.direction, 0);
+        assert!(!swings.has_inputs);
+        assert!(!swings.initialized);
+    }
+
+    #[rstest]
+    fn test_handle_bar_before_warmup_not_initialized() {
+        let mut swings = Swings::new(3);
+        let bar = stub_bar_ethusdt_binance();
+
+        swings.handle_bar(&bar);
+
+        assert!(swings.has_inputs);
+        assert!(!swings.initialized);
+    }
+
+    #[rstest]
+    fn test_reset() {
+        let mut swings = Swings::new(3);
+        let bar = stub_bar_ethusdt_binance();
+
+        for _ in 0..4 {
+            swings.handle_bar(&bar);
+        }
+        swings.reset();
+
+        assert_eq!(swings.count, 0);
+        assert_eq!(swings.direction, 0);
+        assert!(!swings.has_inputs);
+        assert!(!swings.initialized);
+    }
+
+    #[rstest]
+    fn test_length_measures_swing_travel_from_window_extreme() {
+        let mut swings = Swings::new(3);
+        let mut bar = stub_bar_ethusdt_binance();
+
+        // Warm up the window on a flat base, so the first turning point is a real window low
+        // rather than the `0.0` initializer.
+        bar.high = Price::from("100.00");
+        bar.low = Price::from("95.00");
+        for _ in 0..3 {
+            swings.handle_bar(&bar);
+        }
+
+        assert!(swings.initialized);
+        assert_eq!(swings.direction, 1);
+        assert_eq!(swings.length, 5.0); // 100.00 - 95.00, not 100.00 - 0.0
+
+        // A later high extends the swing from the same turning point.
+        bar.high = Price::from("110.00");
+        bar.low = Price::from("96.00");
+        swings.handle_bar(&bar);
+
+        assert_eq!(swings.direction, 1);
+        assert_eq!(swings.length, 15.0); // 110.00 - 95.00
+    }
+}
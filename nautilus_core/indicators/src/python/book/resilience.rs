@@ -1,8 +1,16 @@
-use nautilus_core::python::to_pyvalue_err;
-use nautilus_model::{orderbook::book::OrderBook, enums::OrderSide};
+use nautilus_core::{nanos::UnixNanos, python::to_pyvalue_err};
+use nautilus_model::{
+    data::{quote::QuoteTick, trade::TradeTick},
+    enums::OrderSide,
+    orderbook::book::OrderBook,
+    types::price::Price,
+};
 use pyo3::prelude::*;
 
-use crate::{book::resilience::MarketResilienceIndicator, indicator::Indicator};
+use crate::{
+    book::resilience::{MarketResilienceIndicator, ResilienceIndicatorState},
+    indicator::Indicator,
+};
 
 #[pymethods]
 impl MarketResilienceIndicator {
@@ -19,6 +27,13 @@ impl MarketResilienceIndicator {
         spread_weight: Option<f64>,
         same_side_bias: Option<f64>,
         opposite_side_bias: Option<f64>,
+        impact_notional: Option<f64>,
+        ewma_alpha: Option<f64>,
+        ewma_k: Option<f64>,
+        trade_size_fraction: Option<f64>,
+        trade_spread_tolerance: Option<f64>,
+        trade_weight: Option<f64>,
+        quantile_threshold: Option<f64>,
     ) -> Self {
         Self::new(
             timeout_ms,
@@ -32,6 +47,13 @@ impl MarketResilienceIndicator {
             spread_weight,
             same_side_bias,
             opposite_side_bias,
+            impact_notional,
+            ewma_alpha,
+            ewma_k,
+            trade_size_fraction,
+            trade_spread_tolerance,
+            trade_weight,
+            quantile_threshold,
         )
     }
 
@@ -98,6 +120,20 @@ impl MarketResilienceIndicator {
         self.handle_book(book);
     }
 
+    /// Updates the indicator from a best bid/ask quote tick, for venues where subscribing to
+    /// full `OrderBook` deltas is too expensive.
+    #[pyo3(name = "handle_quote_tick")]
+    fn py_handle_quote_tick(&mut self, quote: &QuoteTick) {
+        self.handle_quote_tick(quote);
+    }
+
+    /// Updates the indicator from a trade tick, driving the trade-recovery path (see
+    /// [`MarketResilienceIndicator::trade_recovery_score`]).
+    #[pyo3(name = "handle_trade_tick")]
+    fn py_handle_trade_tick(&mut self, trade: &TradeTick) {
+        self.handle_trade_tick(trade);
+    }
+
     #[pyo3(name = "reset")]
     fn py_reset(&mut self) {
         self.reset();
@@ -120,4 +156,123 @@ impl MarketResilienceIndicator {
     fn py_is_depletion_continuing(&self) -> bool {
         self.is_depletion_continuing
     }
-} 
\ No newline at end of file
+
+    #[getter]
+    #[pyo3(name = "trade_recovery_score")]
+    fn py_trade_recovery_score(&self) -> f64 {
+        self.trade_recovery_score
+    }
+
+    /// Captures a snapshot of the indicator's current state, for checkpointing a live session
+    /// or a resumable backtest without replaying history.
+    #[pyo3(name = "to_state")]
+    fn py_to_state(&self) -> ResilienceIndicatorState {
+        self.to_state()
+    }
+
+    /// Restores configuration and runtime state captured by `to_state` onto this indicator, so it
+    /// need not already be constructed with matching configuration.
+    #[pyo3(name = "from_state")]
+    fn py_from_state(&mut self, state: ResilienceIndicatorState) {
+        self.from_state(state);
+    }
+}
+
+#[pymethods]
+impl ResilienceIndicatorState {
+    #[getter]
+    #[pyo3(name = "score")]
+    fn py_score(&self) -> f64 {
+        self.score
+    }
+
+    #[getter]
+    #[pyo3(name = "bias_side")]
+    fn py_bias_side(&self) -> OrderSide {
+        self.bias_side
+    }
+
+    #[getter]
+    #[pyo3(name = "depletion_side")]
+    fn py_depletion_side(&self) -> OrderSide {
+        self.depletion_side
+    }
+
+    #[getter]
+    #[pyo3(name = "recovery_side")]
+    fn py_recovery_side(&self) -> OrderSide {
+        self.recovery_side
+    }
+
+    #[getter]
+    #[pyo3(name = "recovery_time")]
+    fn py_recovery_time(&self) -> u64 {
+        self.recovery_time.as_u64()
+    }
+
+    #[getter]
+    #[pyo3(name = "count")]
+    fn py_count(&self) -> usize {
+        self.count
+    }
+
+    #[getter]
+    #[pyo3(name = "initialized")]
+    fn py_initialized(&self) -> bool {
+        self.initialized
+    }
+
+    #[getter]
+    #[pyo3(name = "has_inputs")]
+    fn py_has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    #[getter]
+    #[pyo3(name = "recent_spreads")]
+    fn py_recent_spreads(&self) -> Vec<f64> {
+        self.recent_spreads.clone()
+    }
+
+    #[getter]
+    #[pyo3(name = "ewma_mean")]
+    fn py_ewma_mean(&self) -> f64 {
+        self.ewma_mean
+    }
+
+    #[getter]
+    #[pyo3(name = "ewma_var")]
+    fn py_ewma_var(&self) -> f64 {
+        self.ewma_var
+    }
+
+    #[getter]
+    #[pyo3(name = "ewma_initialized")]
+    fn py_ewma_initialized(&self) -> bool {
+        self.ewma_initialized
+    }
+
+    #[getter]
+    #[pyo3(name = "monitor_initial_price")]
+    fn py_monitor_initial_price(&self) -> Price {
+        self.monitor_initial_price
+    }
+
+    #[getter]
+    #[pyo3(name = "monitor_start_time")]
+    fn py_monitor_start_time(&self) -> Option<u64> {
+        self.monitor_start_time.map(UnixNanos::as_u64)
+    }
+
+    #[getter]
+    #[pyo3(name = "monitor_end_time")]
+    fn py_monitor_end_time(&self) -> Option<u64> {
+        self.monitor_end_time.map(UnixNanos::as_u64)
+    }
+
+    #[getter]
+    #[pyo3(name = "trade_recovery_score")]
+    fn py_trade_recovery_score(&self) -> f64 {
+        self.trade_recovery_score
+    }
+}
\ No newline at end of file
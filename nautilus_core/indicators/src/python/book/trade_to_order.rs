@@ -1,4 +1,7 @@
-use nautilus_model::{orderbook::book::OrderBook, data::trade::TradeTick};
+use nautilus_model::{
+    orderbook::{book::OrderBook, delta::OrderBookDelta},
+    data::trade::TradeTick,
+};
 use pyo3::prelude::*;
 
 use crate::{book::trade_to_order::TradeToOrderRatio, indicator::Indicator};
@@ -6,9 +9,13 @@ use crate::{book::trade_to_order::TradeToOrderRatio, indicator::Indicator};
 #[pymethods]
 impl TradeToOrderRatio {
     #[new]
-    #[pyo3(signature = (depth = 20))]
-    fn py_new(depth: usize) -> Self {
-        Self::new(depth)
+    #[pyo3(signature = (depth = 20, half_life_ns = None))]
+    fn py_new(depth: usize, half_life_ns: Option<u64>) -> Self {
+        let ratio = Self::new(depth);
+        match half_life_ns {
+            Some(half_life_ns) => ratio.with_half_life(half_life_ns),
+            None => ratio,
+        }
     }
 
     fn __repr__(&self) -> String {
@@ -50,11 +57,43 @@ impl TradeToOrderRatio {
         self.handle_book(book);
     }
 
+    #[pyo3(name = "handle_book_delta")]
+    fn py_handle_book_delta(&mut self, delta: &OrderBookDelta) {
+        self.handle_book_delta(delta);
+    }
+
     #[pyo3(name = "handle_trade_tick")]
     fn py_handle_trade_tick(&mut self, trade: &TradeTick) {
         self.handle_trade_tick(trade);
     }
 
+    /// Steps the indicator over a batch of order books in one FFI crossing.
+    #[pyo3(name = "handle_books")]
+    fn py_handle_books(&mut self, py: Python<'_>, books: Vec<OrderBook>) {
+        py.allow_threads(|| {
+            for book in &books {
+                self.handle_book(book);
+            }
+        });
+    }
+
+    /// Steps the indicator over a batch of trade ticks in one FFI crossing.
+    #[pyo3(name = "handle_trade_ticks")]
+    fn py_handle_trade_ticks(&mut self, py: Python<'_>, trades: Vec<TradeTick>) {
+        py.allow_threads(|| {
+            for trade in &trades {
+                self.handle_trade_tick(trade);
+            }
+        });
+    }
+
+    /// Returns the full series of computed ratio values as a NumPy-friendly array.
+    #[getter]
+    #[pyo3(name = "values")]
+    fn py_values(&self) -> Vec<f64> {
+        self.values().to_vec()
+    }
+
     #[pyo3(name = "reset")]
     fn py_reset(&mut self) {
         self.reset();
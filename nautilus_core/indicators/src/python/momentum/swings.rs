@@ -0,0 +1,98 @@
+use nautilus_model::data::bar::Bar;
+use pyo3::prelude::*;
+
+use crate::{indicator::Indicator, momentum::swings::Swings};
+
+#[pymethods]
+impl Swings {
+    #[new]
+    fn py_new(period: usize) -> Self {
+        Self::new(period)
+    }
+
+    fn __repr__(&self) -> String {
+        self.to_string()
+    }
+
+    #[getter]
+    #[pyo3(name = "name")]
+    fn py_name(&self) -> String {
+        self.name()
+    }
+
+    #[getter]
+    #[pyo3(name = "direction")]
+    fn py_direction(&self) -> i8 {
+        self.direction
+    }
+
+    #[getter]
+    #[pyo3(name = "changed")]
+    fn py_changed(&self) -> bool {
+        self.changed
+    }
+
+    #[getter]
+    #[pyo3(name = "high_price")]
+    fn py_high_price(&self) -> f64 {
+        self.high_price
+    }
+
+    #[getter]
+    #[pyo3(name = "low_price")]
+    fn py_low_price(&self) -> f64 {
+        self.low_price
+    }
+
+    #[getter]
+    #[pyo3(name = "high_datetime")]
+    fn py_high_datetime(&self) -> u64 {
+        self.high_datetime.as_u64()
+    }
+
+    #[getter]
+    #[pyo3(name = "low_datetime")]
+    fn py_low_datetime(&self) -> u64 {
+        self.low_datetime.as_u64()
+    }
+
+    #[getter]
+    #[pyo3(name = "since_high")]
+    fn py_since_high(&self) -> usize {
+        self.since_high
+    }
+
+    #[getter]
+    #[pyo3(name = "since_low")]
+    fn py_since_low(&self) -> usize {
+        self.since_low
+    }
+
+    #[getter]
+    #[pyo3(name = "length")]
+    fn py_length(&self) -> f64 {
+        self.length
+    }
+
+    #[getter]
+    #[pyo3(name = "has_inputs")]
+    fn py_has_inputs(&self) -> bool {
+        self.has_inputs()
+    }
+
+    #[getter]
+    #[pyo3(name = "initialized")]
+    fn py_initialized(&self) -> bool {
+        self.initialized
+    }
+
+    #[pyo3(name = "handle_bar")]
+    fn py_handle_bar(&mut self, bar: &Bar) {
+        self.handle_bar(bar);
+    }
+
+    #[pyo3(name = "reset")]
+    fn py_reset(&mut self) {
+        self.reset();
+    }
+}
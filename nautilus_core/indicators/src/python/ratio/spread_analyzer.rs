@@ -0,0 +1,67 @@
+use nautilus_model::{data::quote::QuoteTick, identifiers::InstrumentId};
+use pyo3::prelude::*;
+
+use crate::{indicator::Indicator, ratio::spread_analyzer::SpreadAnalyzer};
+
+#[pymethods]
+impl SpreadAnalyzer {
+    #[new]
+    fn py_new(capacity: usize, instrument_id: InstrumentId) -> Self {
+        Self::new(capacity, instrument_id)
+    }
+
+    fn __repr__(&self) -> String {
+        self.to_string()
+    }
+
+    #[getter]
+    #[pyo3(name = "name")]
+    fn py_name(&self) -> String {
+        self.name()
+    }
+
+    #[getter]
+    #[pyo3(name = "current")]
+    fn py_current(&self) -> f64 {
+        self.current
+    }
+
+    #[getter]
+    #[pyo3(name = "average")]
+    fn py_average(&self) -> f64 {
+        self.average
+    }
+
+    #[getter]
+    #[pyo3(name = "count")]
+    fn py_count(&self) -> usize {
+        self.count
+    }
+
+    #[getter]
+    #[pyo3(name = "has_inputs")]
+    fn py_has_inputs(&self) -> bool {
+        self.has_inputs()
+    }
+
+    #[getter]
+    #[pyo3(name = "initialized")]
+    fn py_initialized(&self) -> bool {
+        self.initialized
+    }
+
+    #[pyo3(name = "handle_quote_tick")]
+    fn py_handle_quote_tick(&mut self, quote: &QuoteTick) {
+        self.handle_quote_tick(quote);
+    }
+
+    #[pyo3(name = "reset")]
+    fn py_reset(&mut self) {
+        self.reset();
+    }
+
+    #[pyo3(name = "reset_calculation")]
+    fn py_reset_calculation(&mut self) {
+        self.reset_calculation();
+    }
+}
@@ -0,0 +1,195 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::VecDeque;
+use std::fmt::Display;
+
+use nautilus_model::{data::quote::QuoteTick, identifiers::InstrumentId};
+
+use crate::indicator::Indicator;
+
+/// An indicator which tracks the bid/ask spread of a single instrument over a capacity-bounded
+/// rolling window.
+///
+/// This gives a lightweight liquidity/cost signal: `current` is the most recent spread and
+/// `average` is the mean spread over the window, becoming `initialized` once the window is full.
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")
+)]
+pub struct SpreadAnalyzer {
+    pub current: f64,
+    pub average: f64,
+    pub count: usize,
+    pub initialized: bool,
+    has_inputs: bool,
+    capacity: usize,
+    instrument_id: InstrumentId,
+    spreads: VecDeque<f64>,
+}
+
+impl Display for SpreadAnalyzer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.name(), self.capacity)
+    }
+}
+
+impl Indicator for SpreadAnalyzer {
+    fn name(&self) -> String {
+        stringify!(SpreadAnalyzer).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_quote_tick(&mut self, quote: &QuoteTick) {
+        if quote.instrument_id != self.instrument_id {
+            return;
+        }
+
+        let spread = quote.ask_price.as_f64() - quote.bid_price.as_f64();
+
+        self.has_inputs = true;
+        self.current = spread;
+
+        if self.spreads.len() == self.capacity {
+            self.spreads.pop_front();
+        }
+        self.spreads.push_back(spread);
+        self.count += 1;
+
+        self.average = self.spreads.iter().sum::<f64>() / self.spreads.len() as f64;
+
+        if self.spreads.len() == self.capacity {
+            self.initialized = true;
+        }
+    }
+
+    fn reset(&mut self) {
+        self.current = 0.0;
+        self.average = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+        self.spreads.clear();
+    }
+}
+
+impl SpreadAnalyzer {
+    /// Creates a new [`SpreadAnalyzer`] instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - The size of the rolling window of spreads to average over.
+    /// * `instrument_id` - The instrument whose quotes this analyzer tracks.
+    #[must_use]
+    pub fn new(capacity: usize, instrument_id: InstrumentId) -> Self {
+        Self {
+            current: 0.0,
+            average: 0.0,
+            count: 0,
+            has_inputs: false,
+            initialized: false,
+            capacity,
+            instrument_id,
+            spreads: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Resets the derived spread values while keeping the capacity and instrument settings.
+    pub fn reset_calculation(&mut self) {
+        self.current = 0.0;
+        self.average = 0.0;
+        self.spreads.clear();
+        self.count = 0;
+        self.initialized = false;
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::stubs::stub_quote_tick_eth_usdt;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_initialized() {
+        let analyzer = SpreadAnalyzer::new(2, InstrumentId::from("ETH/USDT.BINANCE"));
+        let display_str = format!("{analyzer}");
+        assert_eq!(display_str, "SpreadAnalyzer(2)");
+        assert_eq!(analyzer.current, 0.0);
+        assert_eq!(analyzer.average, 0.0);
+        assert!(!analyzer.has_inputs);
+        assert!(!analyzer.initialized);
+    }
+
+    #[rstest]
+    fn test_handle_quote_tick_tracks_current_and_average() {
+        let mut analyzer = SpreadAnalyzer::new(2, InstrumentId::from("ETH/USDT.BINANCE"));
+        let quote = stub_quote_tick_eth_usdt();
+
+        analyzer.handle_quote_tick(&quote);
+        assert!(analyzer.has_inputs);
+        assert!(!analyzer.initialized);
+
+        analyzer.handle_quote_tick(&quote);
+        assert!(analyzer.initialized);
+        assert_eq!(analyzer.count, 2);
+        assert_eq!(
+            analyzer.current,
+            quote.ask_price.as_f64() - quote.bid_price.as_f64()
+        );
+        assert_eq!(analyzer.average, analyzer.current);
+    }
+
+    #[rstest]
+    fn test_ignores_other_instrument() {
+        let mut analyzer = SpreadAnalyzer::new(2, InstrumentId::from("ETH/USDT.BINANCE"));
+        let mut quote = stub_quote_tick_eth_usdt();
+        quote.instrument_id = InstrumentId::from("BTC/USDT.BINANCE");
+
+        analyzer.handle_quote_tick(&quote);
+
+        assert!(!analyzer.has_inputs);
+        assert_eq!(analyzer.count, 0);
+    }
+
+    #[rstest]
+    fn test_reset() {
+        let mut analyzer = SpreadAnalyzer::new(2, InstrumentId::from("ETH/USDT.BINANCE"));
+        let quote = stub_quote_tick_eth_usdt();
+
+        analyzer.handle_quote_tick(&quote);
+        analyzer.handle_quote_tick(&quote);
+        analyzer.reset();
+
+        assert_eq!(analyzer.count, 0);
+        assert_eq!(analyzer.current, 0.0);
+        assert_eq!(analyzer.average, 0.0);
+        assert!(!analyzer.has_inputs);
+        assert!(!analyzer.initialized);
+    }
+}
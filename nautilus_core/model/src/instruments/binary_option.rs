@@ -0,0 +1,530 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
+
+use nautilus_core::{
+    correctness::{check_equal_u8, check_positive_i64, check_positive_u64},
+    nanos::UnixNanos,
+};
+use rust_decimal::{prelude::FromPrimitive, Decimal};
+use serde::{Deserialize, Serialize};
+use sqlx::{postgres::PgRow, FromRow, Row};
+use ustr::Ustr;
+
+use super::{Instrument, InstrumentAny};
+use crate::{
+    enums::{AssetClass, InstrumentClass, OptionKind},
+    identifiers::{instrument_id::InstrumentId, symbol::Symbol},
+    types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
+};
+
+/// A fixed-payout, event/prediction-market instrument that settles at either `0` or `1` of the
+/// quote currency depending on whether [`Self::outcome`] resolves true.
+///
+/// Because price *is* the market-implied probability of the outcome, [`Self::new`] requires
+/// `min_price`/`max_price` to lie within the open interval `(0, 1)` rather than at the
+/// venue/contract limits a `CurrencyPair` or `CryptoPerpetual` would use. This lets the crate's order-book-driven
+/// indicators (e.g. `TradeToOrderRatio`, `MarketResilienceIndicator`) run directly against an
+/// outcome's order book the same way they would against any other instrument.
+#[repr(C)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
+)]
+#[cfg_attr(feature = "trivial_copy", derive(Copy))]
+pub struct BinaryOption {
+    pub id: InstrumentId,
+    pub raw_symbol: Symbol,
+    /// The outcome this contract resolves on, e.g. `"YES"` for a "Will X happen?" market.
+    pub outcome: Ustr,
+    /// The currency the `0`/`1` payoff settles in.
+    pub quote_currency: Currency,
+    pub price_precision: u8,
+    pub size_precision: u8,
+    pub price_increment: Price,
+    pub size_increment: Quantity,
+    pub maker_fee: Decimal,
+    pub taker_fee: Decimal,
+    pub margin_init: Decimal,
+    pub margin_maint: Decimal,
+    /// The upper price bound, strictly less than `1.0`.
+    pub max_price: Price,
+    /// The lower price bound, strictly greater than `0.0`.
+    pub min_price: Price,
+    pub lot_size: Option<Quantity>,
+    pub max_quantity: Option<Quantity>,
+    pub min_quantity: Option<Quantity>,
+    pub max_notional: Option<Money>,
+    pub min_notional: Option<Money>,
+    /// The timestamp at which the market opens for trading.
+    pub activation_ns: UnixNanos,
+    /// The timestamp at which the market closes and resolves.
+    pub expiration_ns: UnixNanos,
+    pub ts_event: UnixNanos,
+    pub ts_init: UnixNanos,
+}
+
+impl BinaryOption {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: InstrumentId,
+        raw_symbol: Symbol,
+        outcome: Ustr,
+        quote_currency: Currency,
+        price_precision: u8,
+        size_precision: u8,
+        price_increment: Price,
+        size_increment: Quantity,
+        taker_fee: Decimal,
+        maker_fee: Decimal,
+        margin_init: Decimal,
+        margin_maint: Decimal,
+        max_price: Price,
+        min_price: Price,
+        lot_size: Option<Quantity>,
+        max_quantity: Option<Quantity>,
+        min_quantity: Option<Quantity>,
+        max_notional: Option<Money>,
+        min_notional: Option<Money>,
+        activation_ns: UnixNanos,
+        expiration_ns: UnixNanos,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<Self> {
+        check_equal_u8(
+            price_precision,
+            price_increment.precision,
+            stringify!(price_precision),
+            stringify!(price_increment.precision),
+        )?;
+        check_equal_u8(
+            size_precision,
+            size_increment.precision,
+            stringify!(size_precision),
+            stringify!(size_increment.precision),
+        )?;
+        check_positive_i64(price_increment.raw, stringify!(price_increment.raw))?;
+        check_positive_u64(size_increment.raw, stringify!(size_increment.raw))?;
+        anyhow::ensure!(
+            min_price.as_f64() > 0.0,
+            "min_price must be strictly greater than 0.0 for a binary option, was {}",
+            min_price.as_f64()
+        );
+        anyhow::ensure!(
+            max_price.as_f64() < 1.0,
+            "max_price must be strictly less than 1.0 for a binary option, was {}",
+            max_price.as_f64()
+        );
+        anyhow::ensure!(
+            min_price < max_price,
+            "min_price must be less than max_price for a binary option"
+        );
+
+        Ok(Self {
+            id,
+            raw_symbol,
+            outcome,
+            quote_currency,
+            price_precision,
+            size_precision,
+            price_increment,
+            size_increment,
+            maker_fee,
+            taker_fee,
+            margin_init,
+            margin_maint,
+            max_price,
+            min_price,
+            lot_size,
+            max_quantity,
+            min_quantity,
+            max_notional,
+            min_notional,
+            activation_ns,
+            expiration_ns,
+            ts_event,
+            ts_init,
+        })
+    }
+
+    /// Returns the maximum payoff for a position of `quantity`, realized if [`Self::outcome`]
+    /// resolves true: `quantity` units of the quote currency.
+    #[must_use]
+    pub fn max_payoff(&self, quantity: Quantity) -> Money {
+        Money::new(quantity.as_f64(), self.quote_currency)
+    }
+
+    /// Returns the market-implied probability of [`Self::outcome`] resolving true, which is
+    /// simply `price` itself since the contract pays out `1` iff the outcome occurs and `0`
+    /// otherwise.
+    #[must_use]
+    pub fn implied_probability(&self, price: Price) -> Decimal {
+        Decimal::from_f64(price.as_f64()).unwrap_or_default()
+    }
+}
+
+impl PartialEq<Self> for BinaryOption {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for BinaryOption {}
+
+impl Hash for BinaryOption {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl Instrument for BinaryOption {
+    fn into_any(self) -> InstrumentAny {
+        InstrumentAny::BinaryOption(self)
+    }
+
+    fn id(&self) -> InstrumentId {
+        self.id
+    }
+
+    fn raw_symbol(&self) -> Symbol {
+        self.raw_symbol
+    }
+
+    fn asset_class(&self) -> AssetClass {
+        AssetClass::Alternative
+    }
+
+    fn instrument_class(&self) -> InstrumentClass {
+        InstrumentClass::BinaryOption
+    }
+
+    fn underlying(&self) -> Option<Ustr> {
+        None
+    }
+
+    fn quote_currency(&self) -> Currency {
+        self.quote_currency
+    }
+
+    fn base_currency(&self) -> Option<Currency> {
+        None
+    }
+
+    fn settlement_currency(&self) -> Currency {
+        self.quote_currency
+    }
+
+    fn isin(&self) -> Option<Ustr> {
+        None
+    }
+
+    fn is_inverse(&self) -> bool {
+        false
+    }
+
+    fn price_precision(&self) -> u8 {
+        self.price_precision
+    }
+
+    fn size_precision(&self) -> u8 {
+        self.size_precision
+    }
+
+    fn price_increment(&self) -> Price {
+        self.price_increment
+    }
+
+    fn size_increment(&self) -> Quantity {
+        self.size_increment
+    }
+
+    fn multiplier(&self) -> Quantity {
+        // SAFETY: Unwrap safe as using known values
+        Quantity::new(1.0, 0).unwrap()
+    }
+
+    fn lot_size(&self) -> Option<Quantity> {
+        self.lot_size
+    }
+
+    fn max_quantity(&self) -> Option<Quantity> {
+        self.max_quantity
+    }
+
+    fn min_quantity(&self) -> Option<Quantity> {
+        self.min_quantity
+    }
+
+    fn max_price(&self) -> Option<Price> {
+        Some(self.max_price)
+    }
+
+    fn min_price(&self) -> Option<Price> {
+        Some(self.min_price)
+    }
+
+    fn ts_event(&self) -> UnixNanos {
+        self.ts_event
+    }
+
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+
+    fn margin_init(&self) -> Decimal {
+        self.margin_init
+    }
+
+    fn margin_maint(&self) -> Decimal {
+        self.margin_maint
+    }
+
+    fn taker_fee(&self) -> Decimal {
+        self.taker_fee
+    }
+
+    fn maker_fee(&self) -> Decimal {
+        self.maker_fee
+    }
+
+    fn option_kind(&self) -> Option<OptionKind> {
+        None
+    }
+
+    fn exchange(&self) -> Option<Ustr> {
+        None
+    }
+
+    fn strike_price(&self) -> Option<Price> {
+        None
+    }
+
+    fn activation_ns(&self) -> Option<UnixNanos> {
+        Some(self.activation_ns)
+    }
+
+    fn expiration_ns(&self) -> Option<UnixNanos> {
+        Some(self.expiration_ns)
+    }
+
+    fn max_notional(&self) -> Option<Money> {
+        self.max_notional
+    }
+
+    fn min_notional(&self) -> Option<Money> {
+        self.min_notional
+    }
+}
+
+impl<'r> FromRow<'r, PgRow> for BinaryOption {
+    fn from_row(row: &'r PgRow) -> Result<Self, sqlx::Error> {
+        let id = row
+            .try_get::<String, _>("id")
+            .map(|res| InstrumentId::from(res.as_str()))?;
+        let raw_symbol = row
+            .try_get::<String, _>("raw_symbol")
+            .map(|res| Symbol::from(res.as_str()))?;
+        let outcome = row
+            .try_get::<String, _>("outcome")
+            .map(|res| Ustr::from(res.as_str()))?;
+        let quote_currency = row
+            .try_get::<String, _>("quote_currency")
+            .map(|res| Currency::from(res.as_str()))?;
+        let price_precision = row.try_get::<i32, _>("price_precision")?;
+        let size_precision = row.try_get::<i32, _>("size_precision")?;
+        let price_increment = row
+            .try_get::<String, _>("price_increment")
+            .map(|res| Price::from(res.as_str()))?;
+        let size_increment = row
+            .try_get::<String, _>("size_increment")
+            .map(|res| Quantity::from(res.as_str()))?;
+        let maker_fee = row
+            .try_get::<String, _>("maker_fee")
+            .map(|res| Decimal::from_str(res.as_str()).unwrap())?;
+        let taker_fee = row
+            .try_get::<String, _>("taker_fee")
+            .map(|res| Decimal::from_str(res.as_str()).unwrap())?;
+        let margin_init = row
+            .try_get::<String, _>("margin_init")
+            .map(|res| Decimal::from_str(res.as_str()).unwrap())?;
+        let margin_maint = row
+            .try_get::<String, _>("margin_maint")
+            .map(|res| Decimal::from_str(res.as_str()).unwrap())?;
+        let max_price = row
+            .try_get::<String, _>("max_price")
+            .map(|res| Price::from(res.as_str()))?;
+        let min_price = row
+            .try_get::<String, _>("min_price")
+            .map(|res| Price::from(res.as_str()))?;
+        let lot_size = row
+            .try_get::<Option<String>, _>("lot_size")
+            .ok()
+            .and_then(|res| res.map(|res| Quantity::from(res.as_str())));
+        let max_quantity = row
+            .try_get::<Option<String>, _>("max_quantity")
+            .ok()
+            .and_then(|res| res.map(|res| Quantity::from(res.as_str())));
+        let min_quantity = row
+            .try_get::<Option<String>, _>("min_quantity")
+            .ok()
+            .and_then(|res| res.map(|res| Quantity::from(res.as_str())));
+        let max_notional = row
+            .try_get::<Option<String>, _>("max_notional")
+            .ok()
+            .and_then(|res| res.map(|res| Money::from(res.as_str())));
+        let min_notional = row
+            .try_get::<Option<String>, _>("min_notional")
+            .ok()
+            .and_then(|res| res.map(|res| Money::from(res.as_str())));
+        let activation_ns = row
+            .try_get::<String, _>("activation_ns")
+            .map(|res| UnixNanos::from(res.as_str()))?;
+        let expiration_ns = row
+            .try_get::<String, _>("expiration_ns")
+            .map(|res| UnixNanos::from(res.as_str()))?;
+        let ts_event = row
+            .try_get::<String, _>("ts_event")
+            .map(|res| UnixNanos::from(res.as_str()))?;
+        let ts_init = row
+            .try_get::<String, _>("ts_init")
+            .map(|res| UnixNanos::from(res.as_str()))?;
+        Ok(Self::new(
+            id,
+            raw_symbol,
+            outcome,
+            quote_currency,
+            price_precision as u8,
+            size_precision as u8,
+            price_increment,
+            size_increment,
+            taker_fee,
+            maker_fee,
+            margin_init,
+            margin_maint,
+            max_price,
+            min_price,
+            lot_size,
+            max_quantity,
+            min_quantity,
+            max_notional,
+            min_notional,
+            activation_ns,
+            expiration_ns,
+            ts_event,
+            ts_init,
+        )
+        .unwrap())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn binary_option_yes() -> BinaryOption {
+        BinaryOption::new(
+            InstrumentId::from("WILL-X-HAPPEN-YES.PREDICTX"),
+            Symbol::from("WILL-X-HAPPEN-YES"),
+            Ustr::from("YES"),
+            Currency::from("USDC"),
+            2,
+            0,
+            Price::new(0.01, 2).unwrap(),
+            Quantity::new(1.0, 0).unwrap(),
+            dec!(0),
+            dec!(0),
+            dec!(0),
+            dec!(0),
+            Price::new(0.99, 2).unwrap(),
+            Price::new(0.01, 2).unwrap(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            UnixNanos::default(),
+            UnixNanos::from(86_400 * 1_000_000_000),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        )
+        .unwrap()
+    }
+
+    #[rstest]
+    fn test_equality() {
+        let option = binary_option_yes();
+        let cloned = option.clone();
+        assert_eq!(option, cloned);
+    }
+
+    #[rstest]
+    fn test_price_bounds_must_lie_strictly_within_zero_and_one() {
+        let mut option = binary_option_yes();
+        option.max_price = Price::new(1.0, 2).unwrap();
+        option.min_price = Price::new(0.0, 2).unwrap();
+
+        let result = BinaryOption::new(
+            option.id,
+            option.raw_symbol,
+            option.outcome,
+            option.quote_currency,
+            option.price_precision,
+            option.size_precision,
+            option.price_increment,
+            option.size_increment,
+            option.taker_fee,
+            option.maker_fee,
+            option.margin_init,
+            option.margin_maint,
+            option.max_price,
+            option.min_price,
+            None,
+            None,
+            None,
+            None,
+            None,
+            option.activation_ns,
+            option.expiration_ns,
+            option.ts_event,
+            option.ts_init,
+        );
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_implied_probability_equals_price() {
+        let option = binary_option_yes();
+        let probability = option.implied_probability(Price::new(0.35, 2).unwrap());
+        assert_eq!(probability, dec!(0.35));
+    }
+
+    #[rstest]
+    fn test_max_payoff_pays_quantity_in_quote_currency() {
+        let option = binary_option_yes();
+        let payoff = option.max_payoff(Quantity::new(100.0, 0).unwrap());
+        assert_eq!(payoff, Money::new(100.0, Currency::from("USDC")));
+    }
+}
@@ -0,0 +1,597 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
+
+use nautilus_core::{
+    correctness::{check_equal_u8, check_positive_i64, check_positive_u64},
+    nanos::UnixNanos,
+};
+use rust_decimal::{prelude::FromPrimitive, Decimal};
+use serde::{Deserialize, Serialize};
+use sqlx::{postgres::PgRow, FromRow, Row};
+use ustr::Ustr;
+
+use super::{Instrument, InstrumentAny};
+use crate::{
+    enums::{AssetClass, InstrumentClass, OptionKind},
+    identifiers::{instrument_id::InstrumentId, symbol::Symbol},
+    orderbook::{book::OrderBook, level::BookLevel},
+    types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
+};
+
+/// A perpetual (perpetual swap) futures contract, carrying no expiration but settling funding
+/// payments between longs and shorts at a fixed interval to keep its price anchored to an index.
+///
+/// Modeled on mango-v4's perp market: depletion-resistant funding is derived from the
+/// impact-bid/impact-ask (the average fill price to consume [`Self::impact_quantity`] of depth
+/// on each side of the book) rather than the last traded price, and the resulting premium is
+/// clamped to `[min_funding, max_funding]` to bound the payment each interval.
+#[repr(C)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
+)]
+#[cfg_attr(feature = "trivial_copy", derive(Copy))]
+pub struct CryptoPerpetual {
+    pub id: InstrumentId,
+    pub raw_symbol: Symbol,
+    pub base_currency: Currency,
+    pub quote_currency: Currency,
+    /// Whether the contract is inverse (quoted in the quote currency, settled and margined in
+    /// the base currency) rather than linear (settled and margined in the quote currency).
+    pub is_inverse: bool,
+    pub price_precision: u8,
+    pub size_precision: u8,
+    pub price_increment: Price,
+    pub size_increment: Quantity,
+    /// The contract multiplier applied per unit of size when computing notional value.
+    pub multiplier: Quantity,
+    pub maker_fee: Decimal,
+    pub taker_fee: Decimal,
+    pub margin_init: Decimal,
+    pub margin_maint: Decimal,
+    /// The minimum funding rate permitted per interval.
+    pub min_funding: Decimal,
+    /// The maximum funding rate permitted per interval.
+    pub max_funding: Decimal,
+    /// The notional depth on each side of the book for the impact-bid/impact-ask, expressed in
+    /// quote-currency terms. Stored only: [`Self::funding_rate`] walks the book by
+    /// `impact_quantity` and does not read this field.
+    pub impact_notional: Money,
+    /// The size consumed on each side of the book to derive the impact-bid/impact-ask used for
+    /// funding.
+    pub impact_quantity: Quantity,
+    /// The interval between funding payments.
+    pub funding_interval_ns: UnixNanos,
+    pub lot_size: Option<Quantity>,
+    pub max_quantity: Option<Quantity>,
+    pub min_quantity: Option<Quantity>,
+    pub max_notional: Option<Money>,
+    pub min_notional: Option<Money>,
+    pub max_price: Option<Price>,
+    pub min_price: Option<Price>,
+    pub ts_event: UnixNanos,
+    pub ts_init: UnixNanos,
+}
+
+impl CryptoPerpetual {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: InstrumentId,
+        raw_symbol: Symbol,
+        base_currency: Currency,
+        quote_currency: Currency,
+        is_inverse: bool,
+        price_precision: u8,
+        size_precision: u8,
+        price_increment: Price,
+        size_increment: Quantity,
+        multiplier: Quantity,
+        taker_fee: Decimal,
+        maker_fee: Decimal,
+        margin_init: Decimal,
+        margin_maint: Decimal,
+        min_funding: Decimal,
+        max_funding: Decimal,
+        impact_notional: Money,
+        impact_quantity: Quantity,
+        funding_interval_ns: UnixNanos,
+        lot_size: Option<Quantity>,
+        max_quantity: Option<Quantity>,
+        min_quantity: Option<Quantity>,
+        max_notional: Option<Money>,
+        min_notional: Option<Money>,
+        max_price: Option<Price>,
+        min_price: Option<Price>,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<Self> {
+        check_equal_u8(
+            price_precision,
+            price_increment.precision,
+            stringify!(price_precision),
+            stringify!(price_increment.precision),
+        )?;
+        check_equal_u8(
+            size_precision,
+            size_increment.precision,
+            stringify!(size_precision),
+            stringify!(size_increment.precision),
+        )?;
+        check_positive_i64(price_increment.raw, stringify!(price_increment.raw))?;
+        check_positive_u64(size_increment.raw, stringify!(size_increment.raw))?;
+
+        Ok(Self {
+            id,
+            raw_symbol,
+            base_currency,
+            quote_currency,
+            is_inverse,
+            price_precision,
+            size_precision,
+            price_increment,
+            size_increment,
+            multiplier,
+            maker_fee,
+            taker_fee,
+            margin_init,
+            margin_maint,
+            min_funding,
+            max_funding,
+            impact_notional,
+            impact_quantity,
+            funding_interval_ns,
+            lot_size,
+            max_quantity,
+            min_quantity,
+            max_notional,
+            min_notional,
+            max_price,
+            min_price,
+            ts_event,
+            ts_init,
+        })
+    }
+
+    /// Walks `levels` outward from the best price, accumulating size until it reaches
+    /// `impact_quantity`, and returns the size-weighted average fill price -- the "impact" price
+    /// for that side of the book. Falls back to `None` if the side has less cumulative size than
+    /// `impact_quantity`.
+    fn impact_price<'a>(
+        levels: impl Iterator<Item = &'a BookLevel>,
+        impact_quantity: f64,
+    ) -> Option<f64> {
+        if impact_quantity <= 0.0 {
+            return None;
+        }
+
+        let mut cumulative_size = 0.0;
+        let mut cumulative_notional = 0.0;
+        for level in levels {
+            let size = level.size();
+            let remaining = impact_quantity - cumulative_size;
+            let filled = remaining.min(size);
+            cumulative_notional += filled * level.price.value.as_f64();
+            cumulative_size += filled;
+            if cumulative_size >= impact_quantity {
+                return Some(cumulative_notional / cumulative_size);
+            }
+        }
+        None
+    }
+
+    /// Computes the funding rate for the next interval from the premium between the book's
+    /// impact-bid/impact-ask and `index_price`, clamped to `[min_funding, max_funding]`.
+    ///
+    /// Falls back to `mark_price` for either side of the book that does not have
+    /// `impact_quantity` of depth available.
+    #[must_use]
+    pub fn funding_rate(&self, mark_price: Price, index_price: Price, book: &OrderBook) -> Decimal {
+        let impact_quantity = self.impact_quantity.as_f64();
+        let impact_bid =
+            Self::impact_price(book.bids(), impact_quantity).unwrap_or_else(|| mark_price.as_f64());
+        let impact_ask =
+            Self::impact_price(book.asks(), impact_quantity).unwrap_or_else(|| mark_price.as_f64());
+        let impact_mid = (impact_bid + impact_ask) / 2.0;
+
+        let index = index_price.as_f64();
+        let premium = if index == 0.0 {
+            0.0
+        } else {
+            (impact_mid - index) / index
+        };
+
+        let rate = Decimal::from_f64(premium).unwrap_or_default();
+        rate.clamp(self.min_funding, self.max_funding)
+    }
+
+    /// Computes the funding payment owed for a position of `position_notional` (positive for
+    /// long, negative for short) at the given funding `rate`.
+    ///
+    /// A positive result is paid by the position holder (funding flows from longs to shorts); a
+    /// negative result is received.
+    #[must_use]
+    pub fn funding_payment(&self, position_notional: Decimal, rate: Decimal) -> Decimal {
+        position_notional * rate
+    }
+}
+
+impl PartialEq<Self> for CryptoPerpetual {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for CryptoPerpetual {}
+
+impl Hash for CryptoPerpetual {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl Instrument for CryptoPerpetual {
+    fn into_any(self) -> InstrumentAny {
+        InstrumentAny::CryptoPerpetual(self)
+    }
+
+    fn id(&self) -> InstrumentId {
+        self.id
+    }
+
+    fn raw_symbol(&self) -> Symbol {
+        self.raw_symbol
+    }
+
+    fn asset_class(&self) -> AssetClass {
+        AssetClass::Cryptocurrency
+    }
+
+    fn instrument_class(&self) -> InstrumentClass {
+        InstrumentClass::Swap
+    }
+
+    fn underlying(&self) -> Option<Ustr> {
+        None
+    }
+
+    fn quote_currency(&self) -> Currency {
+        self.quote_currency
+    }
+
+    fn base_currency(&self) -> Option<Currency> {
+        Some(self.base_currency)
+    }
+
+    fn settlement_currency(&self) -> Currency {
+        if self.is_inverse {
+            self.base_currency
+        } else {
+            self.quote_currency
+        }
+    }
+
+    fn isin(&self) -> Option<Ustr> {
+        None
+    }
+
+    fn is_inverse(&self) -> bool {
+        self.is_inverse
+    }
+
+    fn price_precision(&self) -> u8 {
+        self.price_precision
+    }
+
+    fn size_precision(&self) -> u8 {
+        self.size_precision
+    }
+
+    fn price_increment(&self) -> Price {
+        self.price_increment
+    }
+
+    fn size_increment(&self) -> Quantity {
+        self.size_increment
+    }
+
+    fn multiplier(&self) -> Quantity {
+        self.multiplier
+    }
+
+    fn lot_size(&self) -> Option<Quantity> {
+        self.lot_size
+    }
+
+    fn max_quantity(&self) -> Option<Quantity> {
+        self.max_quantity
+    }
+
+    fn min_quantity(&self) -> Option<Quantity> {
+        self.min_quantity
+    }
+
+    fn max_price(&self) -> Option<Price> {
+        self.max_price
+    }
+
+    fn min_price(&self) -> Option<Price> {
+        self.min_price
+    }
+
+    fn ts_event(&self) -> UnixNanos {
+        self.ts_event
+    }
+
+    fn ts_init(&self) -> UnixNanos {
+        self.ts_init
+    }
+
+    fn margin_init(&self) -> Decimal {
+        self.margin_init
+    }
+
+    fn margin_maint(&self) -> Decimal {
+        self.margin_maint
+    }
+
+    fn taker_fee(&self) -> Decimal {
+        self.taker_fee
+    }
+
+    fn maker_fee(&self) -> Decimal {
+        self.maker_fee
+    }
+
+    fn option_kind(&self) -> Option<OptionKind> {
+        None
+    }
+
+    fn exchange(&self) -> Option<Ustr> {
+        None
+    }
+
+    fn strike_price(&self) -> Option<Price> {
+        None
+    }
+
+    fn activation_ns(&self) -> Option<UnixNanos> {
+        None
+    }
+
+    fn expiration_ns(&self) -> Option<UnixNanos> {
+        None
+    }
+
+    fn max_notional(&self) -> Option<Money> {
+        self.max_notional
+    }
+
+    fn min_notional(&self) -> Option<Money> {
+        self.min_notional
+    }
+}
+
+impl<'r> FromRow<'r, PgRow> for CryptoPerpetual {
+    fn from_row(row: &'r PgRow) -> Result<Self, sqlx::Error> {
+        let id = row
+            .try_get::<String, _>("id")
+            .map(|res| InstrumentId::from(res.as_str()))?;
+        let raw_symbol = row
+            .try_get::<String, _>("raw_symbol")
+            .map(|res| Symbol::from(res.as_str()))?;
+        let base_currency = row
+            .try_get::<String, _>("base_currency")
+            .map(|res| Currency::from(res.as_str()))?;
+        let quote_currency = row
+            .try_get::<String, _>("quote_currency")
+            .map(|res| Currency::from(res.as_str()))?;
+        let is_inverse = row.try_get::<bool, _>("is_inverse")?;
+        let price_precision = row.try_get::<i32, _>("price_precision")?;
+        let size_precision = row.try_get::<i32, _>("size_precision")?;
+        let price_increment = row
+            .try_get::<String, _>("price_increment")
+            .map(|res| Price::from(res.as_str()))?;
+        let size_increment = row
+            .try_get::<String, _>("size_increment")
+            .map(|res| Quantity::from(res.as_str()))?;
+        let multiplier = row
+            .try_get::<String, _>("multiplier")
+            .map(|res| Quantity::from(res.as_str()))?;
+        let maker_fee = row
+            .try_get::<String, _>("maker_fee")
+            .map(|res| Decimal::from_str(res.as_str()).unwrap())?;
+        let taker_fee = row
+            .try_get::<String, _>("taker_fee")
+            .map(|res| Decimal::from_str(res.as_str()).unwrap())?;
+        let margin_init = row
+            .try_get::<String, _>("margin_init")
+            .map(|res| Decimal::from_str(res.as_str()).unwrap())?;
+        let margin_maint = row
+            .try_get::<String, _>("margin_maint")
+            .map(|res| Decimal::from_str(res.as_str()).unwrap())?;
+        let min_funding = row
+            .try_get::<String, _>("min_funding")
+            .map(|res| Decimal::from_str(res.as_str()).unwrap())?;
+        let max_funding = row
+            .try_get::<String, _>("max_funding")
+            .map(|res| Decimal::from_str(res.as_str()).unwrap())?;
+        let impact_notional = row
+            .try_get::<String, _>("impact_notional")
+            .map(|res| Money::from(res.as_str()))?;
+        let impact_quantity = row
+            .try_get::<String, _>("impact_quantity")
+            .map(|res| Quantity::from(res.as_str()))?;
+        let funding_interval_ns = row
+            .try_get::<String, _>("funding_interval_ns")
+            .map(|res| UnixNanos::from(res.as_str()))?;
+        let lot_size = row
+            .try_get::<Option<String>, _>("lot_size")
+            .ok()
+            .and_then(|res| res.map(|res| Quantity::from(res.as_str())));
+        let max_quantity = row
+            .try_get::<Option<String>, _>("max_quantity")
+            .ok()
+            .and_then(|res| res.map(|res| Quantity::from(res.as_str())));
+        let min_quantity = row
+            .try_get::<Option<String>, _>("min_quantity")
+            .ok()
+            .and_then(|res| res.map(|res| Quantity::from(res.as_str())));
+        let max_notional = row
+            .try_get::<Option<String>, _>("max_notional")
+            .ok()
+            .and_then(|res| res.map(|res| Money::from(res.as_str())));
+        let min_notional = row
+            .try_get::<Option<String>, _>("min_notional")
+            .ok()
+            .and_then(|res| res.map(|res| Money::from(res.as_str())));
+        let max_price = row
+            .try_get::<Option<String>, _>("max_price")
+            .ok()
+            .and_then(|res| res.map(|res| Price::from(res.as_str())));
+        let min_price = row
+            .try_get::<Option<String>, _>("min_price")
+            .ok()
+            .and_then(|res| res.map(|res| Price::from(res.as_str())));
+        let ts_event = row
+            .try_get::<String, _>("ts_event")
+            .map(|res| UnixNanos::from(res.as_str()))?;
+        let ts_init = row
+            .try_get::<String, _>("ts_init")
+            .map(|res| UnixNanos::from(res.as_str()))?;
+        Ok(Self::new(
+            id,
+            raw_symbol,
+            base_currency,
+            quote_currency,
+            is_inverse,
+            price_precision as u8,
+            size_precision as u8,
+            price_increment,
+            size_increment,
+            multiplier,
+            taker_fee,
+            maker_fee,
+            margin_init,
+            margin_maint,
+            min_funding,
+            max_funding,
+            impact_notional,
+            impact_quantity,
+            funding_interval_ns,
+            lot_size,
+            max_quantity,
+            min_quantity,
+            max_notional,
+            min_notional,
+            max_price,
+            min_price,
+            ts_event,
+            ts_init,
+        )
+        .unwrap())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::enums::BookType;
+
+    fn crypto_perpetual_btcusdt() -> CryptoPerpetual {
+        CryptoPerpetual::new(
+            InstrumentId::from("BTCUSDT-PERP.BINANCE"),
+            Symbol::from("BTCUSDT-PERP"),
+            Currency::from("BTC"),
+            Currency::from("USDT"),
+            false,
+            2,
+            6,
+            Price::new(0.01, 2).unwrap(),
+            Quantity::new(0.000001, 6).unwrap(),
+            Quantity::new(1.0, 0).unwrap(),
+            dec!(0.0004),
+            dec!(0.0002),
+            dec!(0),
+            dec!(0),
+            dec!(-0.0075),
+            dec!(0.0075),
+            Money::new(10_000.0, Currency::from("USDT")),
+            Quantity::new(1.0, 6).unwrap(),
+            UnixNanos::from(3_600 * 1_000_000_000),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            UnixNanos::default(),
+            UnixNanos::default(),
+        )
+        .unwrap()
+    }
+
+    #[rstest]
+    fn test_equality() {
+        let perpetual = crypto_perpetual_btcusdt();
+        let cloned = perpetual.clone();
+        assert_eq!(perpetual, cloned);
+    }
+
+    #[rstest]
+    fn test_settlement_currency_linear_vs_inverse() {
+        let linear = crypto_perpetual_btcusdt();
+        assert_eq!(linear.settlement_currency(), Currency::from("USDT"));
+
+        let mut inverse = crypto_perpetual_btcusdt();
+        inverse.is_inverse = true;
+        assert_eq!(inverse.settlement_currency(), Currency::from("BTC"));
+        assert!(inverse.is_inverse());
+    }
+
+    #[rstest]
+    fn test_funding_payment() {
+        let perpetual = crypto_perpetual_btcusdt();
+        let payment = perpetual.funding_payment(dec!(10_000), dec!(0.0001));
+        assert_eq!(payment, dec!(1.0000));
+    }
+
+    #[rstest]
+    fn test_funding_rate_clamps_to_max_funding() {
+        let perpetual = crypto_perpetual_btcusdt();
+        let book = OrderBook::new(perpetual.id, BookType::L2_MBP);
+
+        // With no book depth, the impact price falls back to `mark_price` on both sides, so a
+        // mark price far above the index should clamp to `max_funding`.
+        let rate = perpetual.funding_rate(
+            Price::new(100_000.0, 2).unwrap(),
+            Price::new(10_000.0, 2).unwrap(),
+            &book,
+        );
+        assert_eq!(rate, perpetual.max_funding);
+    }
+}